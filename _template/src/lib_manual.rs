@@ -0,0 +1,21 @@
+use aoc_common::{ParseError, Rng, Solution};
+
+type Input = Vec<u32>;
+
+fn parse(s: &str) -> Result<Input, ParseError> {
+    s.lines()
+        .map(|line| {
+            line.parse()
+                .map_err(|_| ParseError::at(s, line, "expected a number"))
+        })
+        .collect()
+}
+
+fn answer_part1(_inputs: &Input) -> usize {
+    todo!()
+}
+
+fn answer_part2(_inputs: &Input) -> usize {
+    todo!()
+}
+TEMPLATE_SOLUTION
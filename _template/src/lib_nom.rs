@@ -0,0 +1,30 @@
+use aoc_common::{ParseError, Rng, Solution};
+use nom::{character::complete::digit1, combinator as C, error::VerboseError, Finish};
+
+type IResult<'a, T> = nom::IResult<&'a str, T, VerboseError<&'a str>>;
+
+type Input = Vec<u32>;
+
+fn parse_line(s: &str) -> IResult<'_, u32> {
+    C::map_res(digit1, str::parse)(s)
+}
+
+fn parse(s: &str) -> Result<Input, ParseError> {
+    s.lines()
+        .map(|line| {
+            C::all_consuming(parse_line)(line)
+                .finish()
+                .map(|(_, n)| n)
+                .map_err(|e| ParseError::from_nom(s, e))
+        })
+        .collect()
+}
+
+fn answer_part1(_inputs: &Input) -> usize {
+    todo!()
+}
+
+fn answer_part2(_inputs: &Input) -> usize {
+    todo!()
+}
+TEMPLATE_SOLUTION
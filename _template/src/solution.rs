@@ -0,0 +1,53 @@
+
+/// `size` lines of one number each.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| rng.range(0, 1000).to_string())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub struct TEMPLATE_STRUCT;
+
+impl Solution for TEMPLATE_STRUCT {
+    const DAY: u8 = TEMPLATE_DAY;
+    const INPUT: &'static str = include_str!("inputs");
+
+    type Input = Input;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        parse(s)
+    }
+
+    fn answer_part1(input: &Input) -> usize {
+        answer_part1(input)
+    }
+
+    fn answer_part2(input: &Input) -> usize {
+        answer_part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_INPUT: &str = include_str!("../examples/example.txt");
+
+    #[test]
+    fn _part1() {
+        let inputs = parse(EXAMPLE_INPUT).unwrap();
+        assert_eq!(0, answer_part1(&inputs));
+    }
+    // #[test]
+    // fn _part2() {
+    //     let inputs = parse(EXAMPLE_INPUT).unwrap();
+    //     assert_eq!(0, answer_part2(&inputs));
+    // }
+}
@@ -0,0 +1,129 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::{normalize, ParseError, Solution};
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// The system allocator, counting what goes through it. It is only the global
+/// allocator with the `alloc-stats` feature, as the counting slows down
+/// allocation-heavy days.
+pub struct CountingAlloc;
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+/// Whether allocations are being counted, so that [`measure`] means anything.
+pub const ENABLED: bool = cfg!(feature = "alloc-stats");
+
+fn grow(size: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED.fetch_add(size, Ordering::Relaxed);
+    let live = LIVE.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(live, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        LIVE.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    /// Counted as allocating the new block and then freeing the old one.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            grow(new_size);
+            LIVE.fetch_sub(layout.size(), Ordering::Relaxed);
+        }
+        new_ptr
+    }
+}
+
+/// What a piece of code allocated. Allocations on other threads in the
+/// meantime are counted too.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    pub allocations: usize,
+    pub bytes: usize,
+    /// Highest number of bytes live at once, over what was live at the start.
+    pub peak: usize,
+}
+
+/// Runs `f` and counts its allocations, including those freed again by the
+/// time it returns.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, AllocStats) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = ALLOCATED.load(Ordering::Relaxed);
+    let live = LIVE.load(Ordering::Relaxed);
+    PEAK.store(live, Ordering::Relaxed);
+    let result = f();
+    let stats = AllocStats {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        bytes: ALLOCATED.load(Ordering::Relaxed) - bytes,
+        peak: PEAK.load(Ordering::Relaxed).saturating_sub(live),
+    };
+    (result, stats)
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct AllocReport {
+    pub parse: AllocStats,
+    pub part1: AllocStats,
+    pub part2: AllocStats,
+}
+
+/// Counts the allocations of parsing `input` and of answering each part,
+/// including rendering the answers. Normalising the input is not counted.
+pub fn alloc_report<S: Solution>(input: &str) -> Result<AllocReport, ParseError> {
+    let input = &*normalize(input);
+    let (parsed, parse) = measure(|| S::parse(input));
+    let parsed = parsed?;
+    let (_, part1) = measure(|| S::answer_part1(&parsed).to_string());
+    let (_, part2) = measure(|| S::answer_part2(&parsed).to_string());
+    Ok(AllocReport {
+        parse,
+        part1,
+        part2,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn _measure() {
+        // Called directly, so that this works without the feature too. With it,
+        // other tests allocating in parallel may add to the counts.
+        let layout = Layout::from_size_align(1024, 8).unwrap();
+        let (_, stats) = measure(|| unsafe {
+            let a = CountingAlloc.alloc(layout);
+            let b = CountingAlloc.realloc(a, layout, 4096);
+            CountingAlloc.dealloc(b, Layout::from_size_align(4096, 8).unwrap());
+        });
+        assert!(stats.allocations >= 2);
+        assert!(stats.bytes >= 1024 + 4096);
+        assert!(stats.peak >= 4096);
+    }
+}
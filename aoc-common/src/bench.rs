@@ -0,0 +1,70 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::{normalize, ParseError, Solution};
+
+/// Spread of the durations of a phase over all iterations.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Timings {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Timings {
+    /// Panics if `samples` is empty.
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort_unstable();
+        Timings {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct BenchReport {
+    pub iterations: usize,
+    pub parse: Timings,
+    pub part1: Timings,
+    pub part2: Timings,
+}
+
+fn time<T>(iterations: usize, mut f: impl FnMut() -> T) -> Timings {
+    let samples = (0..iterations)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+    Timings::from_samples(samples)
+}
+
+/// Times parsing `input` and answering each part separately, `iterations`
+/// times each. At least one iteration is always run. Normalising the input is
+/// not part of the timings.
+pub fn bench<S: Solution>(input: &str, iterations: usize) -> Result<BenchReport, ParseError> {
+    let iterations = iterations.max(1);
+    let input = &*normalize(input);
+    let parsed = S::parse(input)?;
+    Ok(BenchReport {
+        iterations,
+        parse: time(iterations, || S::parse(black_box(input))),
+        part1: time(iterations, || S::answer_part1(black_box(&parsed))),
+        part2: time(iterations, || S::answer_part2(black_box(&parsed))),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn _from_samples() {
+        let ms = Duration::from_millis;
+        let t = Timings::from_samples(vec![ms(5), ms(1), ms(3), ms(9), ms(2)]);
+        assert_eq!((ms(1), ms(3), ms(9)), (t.min, t.median, t.max));
+    }
+}
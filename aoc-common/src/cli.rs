@@ -0,0 +1,563 @@
+use std::borrow::Cow;
+use std::io::{self, IsTerminal, Read};
+use std::path::Path;
+use std::process::ExitCode;
+
+use crate::alloc::{self, alloc_report, AllocReport, AllocStats};
+use crate::bench::{bench, BenchReport, Timings};
+use crate::{normalize, Answers, DynSolution, Part, Solution};
+
+const USAGE: &str = "\
+Usage: {bin} [--format <text|json>] [--bench N] [--allocs] [--check] [--report] [--render PATH] [--] [INPUT...]
+
+Reads the puzzle input from the file INPUT, or from stdin if INPUT is '-' or
+input is piped in. Without either, the input bundled with the binary is used.
+Arguments after '--' are all INPUTs, even those starting with a dash.
+Days whose inputs can be combined, such as day 1, take several INPUT files
+and merge them into one input. Each must be a valid input on its own, and
+errors name the file they are in.
+
+Day 1 also takes `--stream [INPUT]` in place of every other option, to answer
+from a calorie list of any size in constant memory.
+
+With --bench, parsing and both parts are each run N times and the minimum,
+median and maximum durations are reported instead of the answers.
+
+With --allocs, the allocations, bytes allocated and peak live bytes of parsing
+and of each part are reported instead. This needs a build with the alloc-stats
+feature, e.g. `cargo run -p dayN --features aoc-common/alloc-stats`.
+
+With --check, the input is validated against the assumptions the solvers make
+and every problem found is reported, without solving.
+
+With --report, a report on the input is printed instead, for days that have
+one.
+
+With --render, the puzzle is drawn to the image PATH instead, as PPM if it ends
+in '.ppm' and as PNG otherwise.";
+
+/// How answers are written to stdout.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Text,
+    /// A single JSON object per run.
+    Json,
+}
+
+impl Format {
+    pub fn from_name(s: &str) -> Option<Format> {
+        match s {
+            "text" => Some(Format::Text),
+            "json" => Some(Format::Json),
+            _ => None,
+        }
+    }
+}
+
+struct Args {
+    inputs: Vec<String>,
+    format: Format,
+    bench: Option<usize>,
+    allocs: bool,
+    check: bool,
+    report: bool,
+    render: Option<String>,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut inputs = Vec::new();
+    let mut format = Format::Text;
+    let mut bench = None;
+    let mut allocs = false;
+    let mut check = false;
+    let mut report = false;
+    let mut render = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Err(String::new()),
+            "--format" => {
+                let name = args.next().ok_or("--format needs a value")?;
+                format =
+                    Format::from_name(&name).ok_or_else(|| format!("invalid format '{}'", name))?;
+            }
+            "--bench" => {
+                let n = args.next().ok_or("--bench needs a value")?;
+                let n = n
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|n| *n > 0)
+                    .ok_or_else(|| format!("invalid iteration count '{}'", n))?;
+                bench = Some(n);
+            }
+            "--allocs" => allocs = true,
+            "--check" => check = true,
+            "--report" => report = true,
+            "--render" => {
+                let path = args.next().ok_or("--render needs a value")?;
+                render = Some(path);
+            }
+            "--" => inputs.extend(args.by_ref()),
+            // '-' alone is stdin.
+            _ if arg.starts_with('-') && arg != "-" => {
+                return Err(format!("unknown option '{}'", arg));
+            }
+            _ => inputs.push(arg),
+        }
+    }
+    Ok(Args {
+        inputs,
+        format,
+        bench,
+        allocs,
+        check,
+        report,
+        render,
+    })
+}
+
+/// Loads the input from the files at `paths`, or as [`load_input`] does when
+/// there are none. Several files are only accepted by days with a
+/// [`Solution::MERGE_SEPARATOR`], and are merged after checking that each
+/// parses on its own. Errors are ready to be printed.
+pub fn load_inputs(day: &dyn DynSolution, paths: &[String]) -> Result<Cow<'static, str>, String> {
+    let read = |path: Option<&str>| {
+        load_input(path, day.input()).map_err(|e| match path {
+            Some(path) if paths.len() > 1 => format!("error: failed to read {}: {}", path, e),
+            _ => format!("error: failed to read input: {}", e),
+        })
+    };
+    if paths.len() < 2 {
+        return read(paths.first().map(String::as_str));
+    }
+    let Some(separator) = day.merge_separator() else {
+        return Err(format!(
+            "error: day {} takes a single input, got {}",
+            day.day(),
+            paths.len()
+        ));
+    };
+    let mut files = Vec::with_capacity(paths.len());
+    for path in paths {
+        let file = read(Some(path))?;
+        // Checked one by one so that errors point into the right file.
+        day.try_parse(&file)
+            .map_err(|e| e.in_context(path.as_str()).report())?;
+        files.push(normalize(&file).into_owned());
+    }
+    Ok(Cow::Owned(files.join(separator)))
+}
+
+fn read_stdin() -> io::Result<String> {
+    let mut s = String::new();
+    io::stdin().read_to_string(&mut s)?;
+    Ok(s)
+}
+
+/// Loads puzzle input from `path` ('-' meaning stdin), or from stdin when it
+/// is not a terminal. Falls back to `bundled` when neither provides any input.
+pub fn load_input(path: Option<&str>, bundled: &'static str) -> io::Result<Cow<'static, str>> {
+    match path {
+        Some("-") => read_stdin().map(Cow::Owned),
+        Some(path) => std::fs::read_to_string(path).map(Cow::Owned),
+        None if !io::stdin().is_terminal() => {
+            let s = read_stdin()?;
+            if s.is_empty() {
+                Ok(Cow::Borrowed(bundled))
+            } else {
+                Ok(Cow::Owned(s))
+            }
+        }
+        None => Ok(Cow::Borrowed(bundled)),
+    }
+}
+
+/// Prints `Part N = answer`. Multi-line answers start on their own line.
+pub fn print_answer(part: Part, answer: &str) {
+    if answer.contains('\n') {
+        println!("Part {} =\n{}", part.number(), answer);
+    } else {
+        println!("Part {} = {}", part.number(), answer);
+    }
+}
+
+/// Prints the answers of a run on `day` in the given format.
+pub fn print_answers(day: u8, answers: &Answers, format: Format) {
+    match format {
+        Format::Text => {
+            if let Some(answer) = &answers.part1 {
+                print_answer(Part::One, answer);
+            }
+            if let Some(answer) = &answers.part2 {
+                print_answer(Part::Two, answer);
+            }
+        }
+        Format::Json => println!("{}", to_json(day, answers)),
+    }
+}
+
+fn to_json(day: u8, answers: &Answers) -> serde_json::Value {
+    serde_json::json!({
+        "day": day,
+        "part1": answers.part1,
+        "part2": answers.part2,
+        "parse_time_ns": answers.parse_time.as_nanos() as u64,
+        "solve_time_ns": answers.solve_time.as_nanos() as u64,
+    })
+}
+
+/// Prints the timings of a `--bench` run on `day` in the given format.
+pub fn print_bench(day: u8, report: &BenchReport, format: Format) {
+    let phases = [
+        ("parse", &report.parse),
+        ("part 1", &report.part1),
+        ("part 2", &report.part2),
+    ];
+    match format {
+        Format::Text => {
+            println!("Day {}, {} iterations", day, report.iterations);
+            println!("{:<8}{:>12}{:>12}{:>12}", "", "min", "median", "max");
+            for (name, t) in phases {
+                println!(
+                    "{:<8}{:>12.1?}{:>12.1?}{:>12.1?}",
+                    name, t.min, t.median, t.max
+                );
+            }
+        }
+        Format::Json => {
+            let timings = |t: &Timings| {
+                serde_json::json!({
+                    "min_ns": t.min.as_nanos() as u64,
+                    "median_ns": t.median.as_nanos() as u64,
+                    "max_ns": t.max.as_nanos() as u64,
+                })
+            };
+            let json = serde_json::json!({
+                "day": day,
+                "iterations": report.iterations,
+                "parse": timings(&report.parse),
+                "part1": timings(&report.part1),
+                "part2": timings(&report.part2),
+            });
+            println!("{}", json);
+        }
+    }
+}
+
+/// Prints the allocations of an `--allocs` run on `day` in the given format.
+pub fn print_allocs(day: u8, report: &AllocReport, format: Format) {
+    let phases = [
+        ("parse", &report.parse),
+        ("part 1", &report.part1),
+        ("part 2", &report.part2),
+    ];
+    match format {
+        Format::Text => {
+            println!("Day {}", day);
+            println!(
+                "{:<8}{:>12}{:>14}{:>14}",
+                "", "allocs", "bytes", "peak bytes"
+            );
+            for (name, s) in phases {
+                println!(
+                    "{:<8}{:>12}{:>14}{:>14}",
+                    name, s.allocations, s.bytes, s.peak
+                );
+            }
+        }
+        Format::Json => {
+            let stats = |s: &AllocStats| {
+                serde_json::json!({
+                    "allocations": s.allocations,
+                    "bytes": s.bytes,
+                    "peak_bytes": s.peak,
+                })
+            };
+            let json = serde_json::json!({
+                "day": day,
+                "parse": stats(&report.parse),
+                "part1": stats(&report.part1),
+                "part2": stats(&report.part2),
+            });
+            println!("{}", json);
+        }
+    }
+}
+
+/// Checks `input` for `day` and prints every problem found to stderr. Returns
+/// whether the input passed.
+pub fn check(day: &dyn DynSolution, input: &str) -> bool {
+    let problems = day.check(input);
+    for problem in &problems {
+        eprintln!("{}\n", problem.report());
+    }
+    match problems.len() {
+        0 => println!("Day {}: input OK", day.day()),
+        1 => eprintln!("Day {}: 1 problem found", day.day()),
+        n => eprintln!("Day {}: {} problems found", day.day(), n),
+    }
+    problems.is_empty()
+}
+
+/// Prints the report on `input` for `day`. Errors are ready to be printed.
+pub fn report(day: &dyn DynSolution, input: &str) -> Result<(), String> {
+    let report = day
+        .report(input)
+        .map_err(|e| e.report())?
+        .ok_or_else(|| format!("error: day {} has nothing to report", day.day()))?;
+    println!("{}", report);
+    Ok(())
+}
+
+/// Draws `input` for `day` and writes the image to `path`. Errors are ready
+/// to be printed as they are.
+pub fn render(day: &dyn DynSolution, input: &str, path: &str) -> Result<(), String> {
+    let image = day
+        .render(input)
+        .map_err(|e| e.report())?
+        .ok_or_else(|| format!("error: day {} has nothing to render", day.day()))?;
+    crate::image::save(Path::new(path), &image, |&c| c)
+        .map_err(|e| format!("error: failed to write {}: {}", path, e))
+}
+
+/// Entry point shared by every day binary.
+pub fn run<S: Solution + Sync>(solution: S) -> ExitCode {
+    let mut args = std::env::args();
+    let bin = args.next().unwrap_or_else(|| format!("day{}", S::DAY));
+    let args = match parse_args(args) {
+        Ok(args) => args,
+        Err(e) => {
+            if !e.is_empty() {
+                eprintln!("{}\n", e);
+            }
+            eprintln!("{}", USAGE.replace("{bin}", &bin));
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let input = match load_inputs(&solution, &args.inputs) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if let Some(path) = &args.render {
+        return match render(&solution, &input, path) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(e) => {
+                eprintln!("{}", e);
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    if args.report {
+        return match report(&solution, &input) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(e) => {
+                eprintln!("{}", e);
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    if args.check {
+        return if check(&solution, &input) {
+            ExitCode::SUCCESS
+        } else {
+            ExitCode::FAILURE
+        };
+    }
+
+    if args.allocs {
+        if !alloc::ENABLED {
+            eprintln!(
+                "error: --allocs needs a build with the alloc-stats feature, e.g.\n  cargo run -p day{} --features aoc-common/alloc-stats -- --allocs",
+                S::DAY
+            );
+            return ExitCode::FAILURE;
+        }
+        return match alloc_report::<S>(&input) {
+            Ok(report) => {
+                print_allocs(S::DAY, &report, args.format);
+                ExitCode::SUCCESS
+            }
+            Err(e) => {
+                eprintln!("{}", e.report());
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    if let Some(iterations) = args.bench {
+        return match bench::<S>(&input, iterations) {
+            Ok(report) => {
+                print_bench(S::DAY, &report, args.format);
+                ExitCode::SUCCESS
+            }
+            Err(e) => {
+                eprintln!("{}", e.report());
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    let answers = match solution.solve(&input, None) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("{}", e.report());
+            return ExitCode::FAILURE;
+        }
+    };
+    print_answers(S::DAY, &answers, args.format);
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ParseError, Rng};
+
+    fn args(v: &[&str]) -> Result<Args, String> {
+        parse_args(v.iter().map(|s| s.to_string()))
+    }
+
+    #[test]
+    fn _parse_args() {
+        assert!(args(&[]).unwrap().inputs.is_empty());
+        assert_eq!(vec!["big.txt"], args(&["big.txt"]).unwrap().inputs);
+        assert_eq!(
+            vec!["a.txt", "b.txt"],
+            args(&["a.txt", "b.txt"]).unwrap().inputs
+        );
+        assert!(args(&["--help"]).is_err());
+        assert_eq!(vec!["-"], args(&["-"]).unwrap().inputs);
+        assert_eq!(
+            Err("unknown option '--chek'".to_string()),
+            args(&["--chek", "a.txt"]).map(|a| a.inputs)
+        );
+        assert!(args(&["-x"]).is_err());
+        assert_eq!(
+            vec!["a.txt", "--check", "-"],
+            args(&["--check", "--", "a.txt", "--check", "-"])
+                .unwrap()
+                .inputs
+        );
+        assert_eq!(Format::Json, args(&["--format", "json"]).unwrap().format);
+        assert!(args(&["--format", "yaml"]).is_err());
+        assert_eq!(Some(100), args(&["--bench", "100"]).unwrap().bench);
+        assert!(args(&["--bench", "0"]).is_err());
+        assert!(args(&["--allocs"]).unwrap().allocs);
+        assert!(args(&["--check"]).unwrap().check);
+        assert!(args(&["--report"]).unwrap().report);
+        assert_eq!(
+            Some("out.png".to_string()),
+            args(&["--render", "out.png"]).unwrap().render
+        );
+    }
+
+    #[test]
+    fn _to_json() {
+        let answers = Answers {
+            part1: Some("24000".to_string()),
+            part2: None,
+            ..Answers::default()
+        };
+        let json = to_json(1, &answers);
+        assert_eq!(1, json["day"]);
+        assert_eq!("24000", json["part1"]);
+        assert!(json["part2"].is_null());
+        assert_eq!(0, json["parse_time_ns"]);
+    }
+
+    /// One number per line.
+    fn numbers(s: &str) -> Result<Vec<u32>, ParseError> {
+        s.lines()
+            .map(|line| {
+                line.parse()
+                    .map_err(|_| ParseError::at(s, line, "expected a number"))
+            })
+            .collect()
+    }
+
+    struct Single;
+    struct Merging;
+
+    macro_rules! numbers_solution {
+        ($day:ty, $separator:expr) => {
+            impl Solution for $day {
+                const DAY: u8 = 0;
+                const INPUT: &'static str = "1";
+                const MERGE_SEPARATOR: Option<&'static str> = $separator;
+
+                type Input = Vec<u32>;
+                type Output1 = u32;
+                type Output2 = u32;
+
+                fn parse(s: &str) -> Result<Vec<u32>, ParseError> {
+                    numbers(s)
+                }
+
+                fn answer_part1(input: &Vec<u32>) -> u32 {
+                    input.iter().sum()
+                }
+
+                fn answer_part2(input: &Vec<u32>) -> u32 {
+                    input.len() as u32
+                }
+
+                fn generate(_rng: &mut Rng, _size: usize) -> String {
+                    String::new()
+                }
+            }
+        };
+    }
+
+    numbers_solution!(Single, None);
+    numbers_solution!(Merging, Some("\n"));
+
+    #[test]
+    fn _load_inputs() {
+        let dir = std::env::temp_dir();
+        let write = |name: &str, s: &str| {
+            let path = dir.join(name);
+            std::fs::write(&path, s).unwrap();
+            path.to_str().unwrap().to_string()
+        };
+        let a = write("aoc_common_load_inputs_a.txt", "1\n2\n");
+        let b = write("aoc_common_load_inputs_b.txt", "3\r\nx\r\n");
+        let c = write("aoc_common_load_inputs_c.txt", "3");
+
+        assert_eq!(
+            "1\n2\n",
+            load_inputs(&Single, std::slice::from_ref(&a)).unwrap()
+        );
+        assert_eq!(
+            "error: day 0 takes a single input, got 2",
+            load_inputs(&Single, &[a.clone(), c.clone()]).unwrap_err()
+        );
+        assert_eq!(
+            "1\n2\n3",
+            load_inputs(&Merging, &[a.clone(), c.clone()]).unwrap()
+        );
+        // Errors point into the file they are in.
+        let e = load_inputs(&Merging, &[a.clone(), b.clone()]).unwrap_err();
+        assert!(e.contains("--> line 2, column 1"), "{}", e);
+        assert!(e.ends_with(&format!("= in {}", b)), "{}", e);
+
+        for path in [a, b, c] {
+            std::fs::remove_file(path).unwrap();
+        }
+    }
+
+    #[test]
+    fn _load_input_from_file() {
+        let path = std::env::temp_dir().join("aoc_common_load_input.txt");
+        std::fs::write(&path, "1\n2").unwrap();
+        let input = load_input(path.to_str(), "bundled").unwrap();
+        assert_eq!("1\n2", input);
+        std::fs::remove_file(path).unwrap();
+    }
+}
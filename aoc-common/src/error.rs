@@ -0,0 +1,195 @@
+use std::fmt;
+
+/// Why and where parsing a puzzle input failed. `line` and `column` are
+/// 1-based, `column` counting bytes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    /// The offending piece of input.
+    pub text: String,
+    /// The whole line the error is on, if known, for [`ParseError::report`].
+    pub source_line: Option<String>,
+    pub message: String,
+    /// What was being parsed, innermost first, such as `["Operation line",
+    /// "Monkey 3"]`.
+    pub context: Vec<String>,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, text: &str, message: impl Into<String>) -> Self {
+        ParseError {
+            line,
+            column,
+            text: text.to_string(),
+            source_line: None,
+            message: message.into(),
+            context: Vec::new(),
+        }
+    }
+
+    /// Builds an error pointing at `at`, which must be a subslice of `input`.
+    pub fn at(input: &str, at: &str, message: impl Into<String>) -> Self {
+        let offset = (at.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+        assert!(offset <= input.len(), "`at` is not a subslice of `input`");
+        let (line, column) = line_and_column(input, offset);
+        let text = at.lines().next().unwrap_or("");
+        let line_start = offset + 1 - column;
+        let source_line = input[line_start..].lines().next().unwrap_or("");
+        ParseError {
+            source_line: Some(source_line.to_string()),
+            ..ParseError::new(line, column, text, message)
+        }
+    }
+
+    /// Builds an error pointing at the end of `input`.
+    pub fn at_end(input: &str, message: impl Into<String>) -> Self {
+        ParseError::at(input, &input[input.len()..], message)
+    }
+
+    /// Adds an outer context to the error, such as the item being parsed.
+    pub fn in_context(mut self, context: impl Into<String>) -> Self {
+        self.context.push(context.into());
+        self
+    }
+
+    /// Converts the error of a nom parser that was run on (part of) `input`.
+    /// The error points at where the innermost parser failed, and the
+    /// `nom::error::context`s it failed in become the error's context.
+    #[cfg(feature = "nom")]
+    pub fn from_nom(input: &str, e: nom::error::VerboseError<&str>) -> Self {
+        use nom::error::{ErrorKind, VerboseErrorKind};
+
+        let Some(&(at, ref kind)) = e.errors.first() else {
+            return ParseError::at_end(input, "invalid input");
+        };
+        let message = match kind {
+            VerboseErrorKind::Char(c) => format!("expected {:?}", c),
+            VerboseErrorKind::Nom(ErrorKind::Eof) => "unexpected trailing input".to_string(),
+            VerboseErrorKind::Nom(ErrorKind::Digit) => "expected a number".to_string(),
+            VerboseErrorKind::Nom(ErrorKind::MapRes) => "number out of range".to_string(),
+            VerboseErrorKind::Nom(code) => format!("unexpected input ({})", code.description()),
+            VerboseErrorKind::Context(context) => format!("invalid {}", context),
+        };
+        let context = e.errors.iter().filter_map(|(_, kind)| match kind {
+            VerboseErrorKind::Context(context) => Some(context.to_string()),
+            _ => None,
+        });
+        ParseError {
+            context: context.collect(),
+            ..ParseError::at(input, at, message)
+        }
+    }
+
+    /// A multi-line, rustc-style report showing the line the error is on with
+    /// a caret under the failing column, followed by the context. The caret
+    /// is placed by chars, so it lines up under non-ASCII text, and a column
+    /// of 0 is treated as 1.
+    pub fn report(&self) -> String {
+        let mut report = format!("error: {}\n", self.message);
+        let number = self.line.to_string();
+        let pad = " ".repeat(number.len());
+        report += &format!("{}--> line {}, column {}\n", pad, self.line, self.column);
+        if let Some(source_line) = &self.source_line {
+            let mut byte = self.column.saturating_sub(1).min(source_line.len());
+            while !source_line.is_char_boundary(byte) {
+                byte -= 1;
+            }
+            let caret = " ".repeat(source_line[..byte].chars().count());
+            report += &format!("{} |\n", pad);
+            report += &format!("{} | {}\n", number, source_line);
+            report += &format!("{} | {}^\n", pad, caret);
+        }
+        if !self.context.is_empty() {
+            report += &format!("{} = in {}\n", pad, self.context.join(" of "));
+        }
+        report.pop();
+        report
+    }
+}
+
+fn line_and_column(input: &str, offset: usize) -> (usize, usize) {
+    let before = &input[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    (line, offset - line_start + 1)
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        if !self.text.is_empty() {
+            write!(f, " (found '{}')", self.text)?;
+        }
+        if !self.context.is_empty() {
+            write!(f, " in {}", self.context.join(" of "))?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn _at() {
+        let input = "ab\ncd ef\ngh";
+        let e = ParseError::at(input, &input[6..], "bad");
+        assert_eq!((2, 4, "ef"), (e.line, e.column, e.text.as_str()));
+        assert_eq!("line 2, column 4: bad (found 'ef')", e.to_string());
+
+        let e = ParseError::at_end(input, "missing");
+        assert_eq!((3, 3, ""), (e.line, e.column, e.text.as_str()));
+        assert_eq!("line 3, column 3: missing", e.to_string());
+    }
+
+    #[test]
+    fn _report() {
+        let input = "ab\ncd ef\ngh";
+        let e = ParseError::at(input, &input[6..], "bad")
+            .in_context("pair")
+            .in_context("block 1");
+        assert_eq!(
+            "line 2, column 4: bad (found 'ef') in pair of block 1",
+            e.to_string()
+        );
+        assert_eq!(
+            "\
+error: bad
+ --> line 2, column 4
+  |
+2 | cd ef
+  |    ^
+  = in pair of block 1",
+            e.report()
+        );
+    }
+
+    #[test]
+    fn _report_caret() {
+        let input = "ä→b c";
+        let e = ParseError::at(input, &input[7..], "bad");
+        assert_eq!(8, e.column);
+        assert_eq!(
+            "error: bad\n --> line 1, column 8\n  |\n1 | ä→b c\n  |     ^",
+            e.report()
+        );
+
+        let e = ParseError {
+            column: 0,
+            source_line: Some("ab".to_string()),
+            ..ParseError::new(1, 0, "", "bad")
+        };
+        assert_eq!(
+            "error: bad\n --> line 1, column 0\n  |\n1 | ab\n  | ^",
+            e.report()
+        );
+    }
+}
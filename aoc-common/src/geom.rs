@@ -0,0 +1,284 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A position or offset on a plane, with `y` growing downwards as in
+/// [`Grid`](crate::Grid).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Vec2 {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// A position or offset in space.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Vec3 {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+/// The smallest box holding a set of points, inclusive at both ends.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Bounds<V> {
+    pub min: V,
+    pub max: V,
+}
+
+/// What [`Bounds`] needs of a vector type.
+pub trait Vector: Copy + Add<Output = Self> + Sub<Output = Self> {
+    /// A vector with every component `n`.
+    fn splat(n: i32) -> Self;
+    /// The component-wise minimum.
+    fn min(self, other: Self) -> Self;
+    /// The component-wise maximum.
+    fn max(self, other: Self) -> Self;
+    /// Whether every component is at most `other`'s.
+    fn all_le(self, other: Self) -> bool;
+}
+
+impl<V: Vector> Bounds<V> {
+    /// The bounding box of `points`, or `None` if there are none.
+    pub fn from_points(points: impl IntoIterator<Item = V>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        let bounds = Bounds {
+            min: first,
+            max: first,
+        };
+        Some(points.fold(bounds, |bounds, p| Bounds {
+            min: bounds.min.min(p),
+            max: bounds.max.max(p),
+        }))
+    }
+
+    pub fn contains(&self, p: V) -> bool {
+        self.min.all_le(p) && p.all_le(self.max)
+    }
+
+    /// Grows the box by `n` in every direction.
+    pub fn grow(self, n: i32) -> Self {
+        Bounds {
+            min: self.min - V::splat(n),
+            max: self.max + V::splat(n),
+        }
+    }
+
+    /// Number of points along each axis.
+    pub fn size(&self) -> V {
+        self.max - self.min + V::splat(1)
+    }
+}
+
+impl Vec2 {
+    pub const ZERO: Vec2 = Vec2::new(0, 0);
+    pub const LEFT: Vec2 = Vec2::new(-1, 0);
+    pub const RIGHT: Vec2 = Vec2::new(1, 0);
+    pub const UP: Vec2 = Vec2::new(0, -1);
+    pub const DOWN: Vec2 = Vec2::new(0, 1);
+
+    pub const fn new(x: i32, y: i32) -> Self {
+        Vec2 { x, y }
+    }
+
+    /// The positions left, right, above and below.
+    pub fn neighbors4(self) -> impl Iterator<Item = Vec2> {
+        [Vec2::LEFT, Vec2::RIGHT, Vec2::UP, Vec2::DOWN]
+            .into_iter()
+            .map(move |d| self + d)
+    }
+
+    /// The positions around, including diagonals, row by row.
+    pub fn neighbors8(self) -> impl Iterator<Item = Vec2> {
+        (-1..=1)
+            .flat_map(|dy| (-1..=1).map(move |dx| Vec2::new(dx, dy)))
+            .filter(|&d| d != Vec2::ZERO)
+            .map(move |d| self + d)
+    }
+}
+
+impl Vec3 {
+    pub const ZERO: Vec3 = Vec3::new(0, 0, 0);
+
+    pub const fn new(x: i32, y: i32, z: i32) -> Self {
+        Vec3 { x, y, z }
+    }
+
+    /// The positions sharing a face.
+    pub fn neighbors6(self) -> impl Iterator<Item = Vec3> {
+        [
+            Vec3::new(-1, 0, 0),
+            Vec3::new(1, 0, 0),
+            Vec3::new(0, -1, 0),
+            Vec3::new(0, 1, 0),
+            Vec3::new(0, 0, -1),
+            Vec3::new(0, 0, 1),
+        ]
+        .into_iter()
+        .map(move |d| self + d)
+    }
+}
+
+impl From<(i32, i32)> for Vec2 {
+    fn from((x, y): (i32, i32)) -> Self {
+        Vec2 { x, y }
+    }
+}
+
+impl From<Vec2> for (i32, i32) {
+    fn from(v: Vec2) -> Self {
+        (v.x, v.y)
+    }
+}
+
+impl From<(i32, i32, i32)> for Vec3 {
+    fn from((x, y, z): (i32, i32, i32)) -> Self {
+        Vec3 { x, y, z }
+    }
+}
+
+impl From<Vec3> for (i32, i32, i32) {
+    fn from(v: Vec3) -> Self {
+        (v.x, v.y, v.z)
+    }
+}
+
+/// The component-wise operations and distances both vector types share.
+macro_rules! vector {
+    ($name:ident { $($c:ident),+ }) => {
+        impl $name {
+            /// Number of orthogonal steps to `other`.
+            pub fn manhattan(self, other: $name) -> u32 {
+                0 $(+ self.$c.abs_diff(other.$c))+
+            }
+
+            /// Number of steps to `other` when diagonal steps are allowed.
+            pub fn chebyshev(self, other: $name) -> u32 {
+                0u32 $(.max(self.$c.abs_diff(other.$c)))+
+            }
+
+            /// Each component's sign, which turns an offset into a single
+            /// step towards it.
+            pub fn signum(self) -> Self {
+                $name { $($c: self.$c.signum()),+ }
+            }
+        }
+
+        impl Vector for $name {
+            fn splat(n: i32) -> Self {
+                $name { $($c: n),+ }
+            }
+
+            fn min(self, other: $name) -> Self {
+                $name { $($c: self.$c.min(other.$c)),+ }
+            }
+
+            fn max(self, other: $name) -> Self {
+                $name { $($c: self.$c.max(other.$c)),+ }
+            }
+
+            fn all_le(self, other: $name) -> bool {
+                true $(&& self.$c <= other.$c)+
+            }
+        }
+
+        impl Add for $name {
+            type Output = $name;
+
+            fn add(self, other: $name) -> $name {
+                $name { $($c: self.$c + other.$c),+ }
+            }
+        }
+
+        impl Sub for $name {
+            type Output = $name;
+
+            fn sub(self, other: $name) -> $name {
+                $name { $($c: self.$c - other.$c),+ }
+            }
+        }
+
+        impl Mul<i32> for $name {
+            type Output = $name;
+
+            fn mul(self, n: i32) -> $name {
+                $name { $($c: self.$c * n),+ }
+            }
+        }
+
+        impl Neg for $name {
+            type Output = $name;
+
+            fn neg(self) -> $name {
+                $name { $($c: -self.$c),+ }
+            }
+        }
+
+        impl AddAssign for $name {
+            fn add_assign(&mut self, other: $name) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $name {
+            fn sub_assign(&mut self, other: $name) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+vector!(Vec2 { x, y });
+vector!(Vec3 { x, y, z });
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn _arithmetic() {
+        let (a, b) = (Vec2::new(1, -2), Vec2::new(4, 2));
+        assert_eq!(Vec2::new(5, 0), a + b);
+        assert_eq!(Vec2::new(3, 4), b - a);
+        assert_eq!(Vec2::new(-2, 4), -a * 2);
+        assert_eq!(Vec2::new(1, 1), (b - a).signum());
+        let mut c = a;
+        c += Vec2::DOWN;
+        c -= Vec2::LEFT;
+        assert_eq!(Vec2::new(2, -1), c);
+        assert_eq!((5, 7, 9), (Vec3::new(1, 2, 3) + (4, 5, 6).into()).into());
+    }
+
+    #[test]
+    fn _distances() {
+        let (a, b) = (Vec2::new(1, -2), Vec2::new(4, 2));
+        assert_eq!(7, a.manhattan(b));
+        assert_eq!(4, a.chebyshev(b));
+        assert_eq!(6, Vec3::ZERO.manhattan(Vec3::new(-1, 2, 3)));
+        assert_eq!(3, Vec3::ZERO.chebyshev(Vec3::new(-1, 2, 3)));
+    }
+
+    #[test]
+    fn _neighbors() {
+        let n4: Vec<_> = Vec2::ZERO.neighbors4().collect();
+        assert_eq!(vec![Vec2::LEFT, Vec2::RIGHT, Vec2::UP, Vec2::DOWN], n4);
+        assert_eq!(8, Vec2::ZERO.neighbors8().count());
+        assert!(Vec2::ZERO
+            .neighbors8()
+            .all(|n| n.chebyshev(Vec2::ZERO) == 1));
+        assert!(Vec3::ZERO
+            .neighbors6()
+            .all(|n| n.manhattan(Vec3::ZERO) == 1));
+    }
+
+    #[test]
+    fn _bounds() {
+        let points = [Vec3::new(1, 5, 2), Vec3::new(3, 0, 2), Vec3::new(2, 2, -1)];
+        let bounds = Bounds::from_points(points).unwrap();
+        assert_eq!(Vec3::new(1, 0, -1), bounds.min);
+        assert_eq!(Vec3::new(3, 5, 2), bounds.max);
+        assert_eq!(Vec3::new(3, 6, 4), bounds.size());
+        assert!(points.iter().all(|&p| bounds.contains(p)));
+        assert!(!bounds.contains(Vec3::ZERO));
+        assert!(bounds.grow(1).contains(Vec3::ZERO));
+        assert_eq!(None, Bounds::<Vec2>::from_points([]));
+    }
+}
@@ -0,0 +1,320 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::{Bounds, ParseError, Vec2};
+
+/// A rectangular, row-major grid addressed by `(x, y)`, with `(0, 0)` in the
+/// top-left corner.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+const NEIGHBORS_4: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+const NEIGHBORS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+impl<T> Grid<T> {
+    /// Panics if `cells` does not hold exactly `width * height` cells.
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(width * height, cells.len(), "grid size mismatch");
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_vec(width, height, vec![fill; width * height])
+    }
+
+    /// Parses a character map, one row per line, mapping each byte with `f`.
+    /// Accepts both `\n` and `\r\n` line endings and a trailing newline.
+    /// Fails on the first row whose length differs from the first row's.
+    pub fn from_char_map(s: &str, mut f: impl FnMut(u8) -> T) -> Result<Self, ParseError> {
+        let mut width = 0;
+        let mut height = 0;
+        let mut cells = Vec::with_capacity(s.len());
+        for (row, line) in s.lines().enumerate() {
+            if row == 0 {
+                width = line.len();
+            } else if line.len() != width {
+                let message = format!("row has {} cells, expected {}", line.len(), width);
+                let at = line.len().min(width);
+                // Cells are bytes, so back up to a char boundary to slice.
+                let at = &line[(0..=at).rev().find(|&i| line.is_char_boundary(i)).unwrap()..];
+                return Err(ParseError::at(s, at, message));
+            }
+            cells.extend(line.bytes().map(&mut f));
+            height += 1;
+        }
+        Ok(Grid::from_vec(width, height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    /// The positions in the grid, for working with [`Vec2`] positions.
+    pub fn bounds(&self) -> Bounds<Vec2> {
+        Bounds {
+            min: Vec2::ZERO,
+            max: Vec2::new(self.width as i32 - 1, self.height as i32 - 1),
+        }
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if self.in_bounds(x, y) {
+            Some(&self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if self.in_bounds(x, y) {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// All cells in row-major order.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    /// All positions in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Position of the first cell, in row-major order, matching `pred`.
+    pub fn position(&self, pred: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        let idx = self.cells.iter().position(pred)?;
+        Some((idx % self.width, idx / self.width))
+    }
+
+    fn offsets<'a>(
+        &self,
+        (x, y): (usize, usize),
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        let (width, height) = (self.width, self.height);
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let x = x.checked_add_signed(dx).filter(|x| *x < width)?;
+            let y = y.checked_add_signed(dy).filter(|y| *y < height)?;
+            Some((x, y))
+        })
+    }
+
+    /// In-bounds positions left, right, above and below `pos`.
+    pub fn neighbors4(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        self.offsets(pos, &NEIGHBORS_4)
+    }
+
+    /// In-bounds positions around `pos`, including diagonals.
+    pub fn neighbors8(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        self.offsets(pos, &NEIGHBORS_8)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.height, "row {} is out of bounds", y);
+        &self.cells[(y * self.width)..((y + 1) * self.width)]
+    }
+
+    /// Every row, top to bottom. A grid with no columns still has `height`
+    /// rows, each of them empty.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(move |y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} is out of bounds", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    /// Mirrors the grid along its main diagonal, so rows become columns.
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = self.columns().flat_map(|c| c.cloned()).collect();
+        Grid::from_vec(self.height, self.width, cells)
+    }
+
+    /// Rotates the grid a quarter turn clockwise.
+    pub fn rotate_cw(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width)
+            .flat_map(|x| (0..self.height).rev().map(move |y| self[(x, y)].clone()))
+            .collect();
+        Grid::from_vec(self.height, self.width, cells)
+    }
+
+    /// Rotates the grid a quarter turn counter-clockwise.
+    pub fn rotate_ccw(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width)
+            .rev()
+            .flat_map(|x| (0..self.height).map(move |y| self[(x, y)].clone()))
+            .collect();
+        Grid::from_vec(self.height, self.width, cells)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::from_vec(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(self.in_bounds(x, y), "({}, {}) is out of bounds", x, y);
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(self.in_bounds(x, y), "({}, {}) is out of bounds", x, y);
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+impl<T> Index<Vec2> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, v: Vec2) -> &T {
+        assert!(self.bounds().contains(v), "{:?} is out of bounds", v);
+        &self[(v.x as usize, v.y as usize)]
+    }
+}
+
+impl<T> IndexMut<Vec2> for Grid<T> {
+    fn index_mut(&mut self, v: Vec2) -> &mut T {
+        assert!(self.bounds().contains(v), "{:?} is out of bounds", v);
+        &mut self[(v.x as usize, v.y as usize)]
+    }
+}
+
+/// Displays one line per row, with the cells of a row written back to back.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                cell.fmt(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(s: &str) -> Grid<u8> {
+        Grid::from_char_map(s, |c| c - b'0').unwrap()
+    }
+
+    #[test]
+    fn _from_char_map() {
+        let grid = digits("123\n456\n");
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(digits("123\r\n456"), grid);
+        assert_eq!(Some(&6), grid.get(2, 1));
+        assert_eq!(None, grid.get(3, 1));
+        let e = Grid::from_char_map("123\n45", |c| c).unwrap_err();
+        assert_eq!((2, 3), (e.line, e.column));
+    }
+
+    #[test]
+    fn _neighbors() {
+        let grid = digits("123\n456\n789");
+        let n4: Vec<_> = grid.neighbors4((0, 0)).collect();
+        assert_eq!(vec![(1, 0), (0, 1)], n4);
+        assert_eq!(8, grid.neighbors8((1, 1)).count());
+        assert_eq!(5, grid.neighbors8((1, 0)).count());
+    }
+
+    #[test]
+    fn _vec2_index() {
+        let mut grid = digits("123\n456");
+        assert_eq!(6, grid[Vec2::new(2, 1)]);
+        grid[Vec2::new(0, 1)] = 0;
+        assert_eq!(&[0, 5, 6], grid.row(1));
+        assert!(grid.bounds().contains(Vec2::new(2, 1)));
+        assert!(!grid.bounds().contains(Vec2::new(-1, 0)));
+    }
+
+    #[test]
+    fn _rows_and_columns() {
+        let grid = digits("123\n456");
+        assert_eq!(&[4, 5, 6], grid.row(1));
+        let col: Vec<_> = grid.column(1).copied().collect();
+        assert_eq!(vec![2, 5], col);
+        assert_eq!(3, grid.columns().count());
+        assert_eq!(2, grid.rows().count());
+
+        let empty: Grid<u8> = Grid::new(0, 2, 0);
+        assert_eq!(vec![&[] as &[u8]; 2], empty.rows().collect::<Vec<_>>());
+        assert_eq!(0, empty.columns().count());
+    }
+
+    #[test]
+    #[should_panic(expected = "column 3 is out of bounds")]
+    fn _column_out_of_bounds() {
+        let _ = digits("123\n456").column(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "row 2 is out of bounds")]
+    fn _row_out_of_bounds() {
+        digits("123\n456").row(2);
+    }
+
+    #[test]
+    fn _transpose_and_rotate() {
+        let grid = digits("123\n456");
+        assert_eq!("14\n25\n36", grid.transpose().to_string());
+        assert_eq!("41\n52\n63", grid.rotate_cw().to_string());
+        assert_eq!("36\n25\n14", grid.rotate_ccw().to_string());
+        assert_eq!(grid, grid.rotate_cw().rotate_ccw());
+    }
+}
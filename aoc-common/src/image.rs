@@ -0,0 +1,179 @@
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use crate::Grid;
+
+/// A pixel's red, green and blue components.
+pub type Rgb = [u8; 3];
+
+/// Scales every cell up to a `factor` by `factor` block, for grids too small
+/// to make out at one pixel per cell.
+pub fn upscale<T: Clone>(grid: &Grid<T>, factor: usize) -> Grid<T> {
+    let (width, height) = (grid.width() * factor, grid.height() * factor);
+    let cells = (0..height)
+        .flat_map(|y| (0..width).map(move |x| grid[(x / factor, y / factor)].clone()))
+        .collect();
+    Grid::from_vec(width, height, cells)
+}
+
+/// Writes a binary PPM (P6) image.
+pub fn write_ppm<T>(
+    grid: &Grid<T>,
+    mut color: impl FnMut(&T) -> Rgb,
+    out: &mut impl Write,
+) -> io::Result<()> {
+    write!(out, "P6\n{} {}\n255\n", grid.width(), grid.height())?;
+    for cell in grid.cells() {
+        out.write_all(&color(cell))?;
+    }
+    Ok(())
+}
+
+/// Writes an 8-bit RGB PNG image. The pixel data is stored uncompressed,
+/// which keeps the encoder small at the cost of file size.
+pub fn write_png<T>(
+    grid: &Grid<T>,
+    mut color: impl FnMut(&T) -> Rgb,
+    out: &mut impl Write,
+) -> io::Result<()> {
+    if grid.width() == 0 || grid.height() == 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "a PNG image can not be empty",
+        ));
+    }
+    let too_large = || io::Error::new(io::ErrorKind::InvalidInput, "image is too large");
+    let width = u32::try_from(grid.width()).map_err(|_| too_large())?;
+    let height = u32::try_from(grid.height()).map_err(|_| too_large())?;
+
+    // Each row starts with its filter type, which is always "none".
+    let mut raw = Vec::with_capacity((grid.width() * 3 + 1) * grid.height());
+    for row in grid.rows() {
+        raw.push(0);
+        for cell in row {
+            raw.extend_from_slice(&color(cell));
+        }
+    }
+
+    out.write_all(b"\x89PNG\r\n\x1a\n")?;
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&width.to_be_bytes());
+    header.extend_from_slice(&height.to_be_bytes());
+    // Bit depth 8, truecolour, default compression, filtering and no interlacing.
+    header.extend_from_slice(&[8, 2, 0, 0, 0]);
+    write_chunk(out, b"IHDR", &header)?;
+    write_chunk(out, b"IDAT", &zlib_stored(&raw))?;
+    write_chunk(out, b"IEND", &[])
+}
+
+/// Writes `grid` to `path`, as PPM if the extension is `.ppm` and as PNG
+/// otherwise.
+pub fn save<T>(path: &Path, grid: &Grid<T>, color: impl FnMut(&T) -> Rgb) -> io::Result<()> {
+    let mut out = BufWriter::new(File::create(path)?);
+    if path.extension().is_some_and(|ext| ext == "ppm") {
+        write_ppm(grid, color, &mut out)?;
+    } else {
+        write_png(grid, color, &mut out)?;
+    }
+    out.flush()
+}
+
+fn write_chunk(out: &mut impl Write, kind: &[u8; 4], data: &[u8]) -> io::Result<()> {
+    out.write_all(&(data.len() as u32).to_be_bytes())?;
+    out.write_all(kind)?;
+    out.write_all(data)?;
+    let crc = !crc32_update(crc32_update(!0, kind), data);
+    out.write_all(&crc.to_be_bytes())
+}
+
+/// Wraps `data`, which must not be empty, in a zlib stream of uncompressed
+/// deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    const MAX_BLOCK: usize = u16::MAX as usize;
+    let mut out = Vec::with_capacity(data.len() + data.len() / MAX_BLOCK * 5 + 11);
+    out.extend_from_slice(&[0x78, 0x01]);
+    let mut blocks = data.chunks(MAX_BLOCK).peekable();
+    while let Some(block) = blocks.next() {
+        let last = blocks.peek().is_none();
+        let len = block.len() as u16;
+        out.push(last as u8);
+        out.extend_from_slice(&len.to_le_bytes());
+        out.extend_from_slice(&(!len).to_le_bytes());
+        out.extend_from_slice(block);
+    }
+    out.extend_from_slice(&adler32(data).to_be_bytes());
+    out
+}
+
+fn crc32_update(mut crc: u32, data: &[u8]) -> u32 {
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in data {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn checkerboard() -> Grid<bool> {
+        Grid::from_vec(2, 2, vec![true, false, false, true])
+    }
+
+    fn color(&c: &bool) -> Rgb {
+        if c {
+            [255, 255, 255]
+        } else {
+            [0, 0, 0]
+        }
+    }
+
+    #[test]
+    fn _checksums() {
+        assert_eq!(0xcbf4_3926, !crc32_update(!0, b"123456789"));
+        assert_eq!(0x11e6_0398, adler32(b"Wikipedia"));
+    }
+
+    #[test]
+    fn _write_ppm() {
+        let mut out = Vec::new();
+        write_ppm(&checkerboard(), color, &mut out).unwrap();
+        let mut expected = b"P6\n2 2\n255\n".to_vec();
+        expected.extend_from_slice(&[255, 255, 255, 0, 0, 0, 0, 0, 0, 255, 255, 255]);
+        assert_eq!(expected, out);
+    }
+
+    #[test]
+    fn _write_png() {
+        let mut out = Vec::new();
+        write_png(&checkerboard(), color, &mut out).unwrap();
+        assert!(out.starts_with(b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR\0\0\0\x02\0\0\0\x02"));
+        assert!(out.ends_with(b"\0\0\0\0IEND\xae\x42\x60\x82"));
+        let empty = Grid::<bool>::from_vec(0, 0, vec![]);
+        assert!(write_png(&empty, color, &mut Vec::new()).is_err());
+    }
+
+    #[test]
+    fn _upscale() {
+        let grid = upscale(&checkerboard(), 2);
+        assert_eq!((4, 4), (grid.width(), grid.height()));
+        assert_eq!(vec![true, true, false, false], grid.row(1));
+    }
+}
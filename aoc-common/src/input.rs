@@ -0,0 +1,33 @@
+use std::borrow::Cow;
+
+/// Brings puzzle input into the shape every day's `parse` expects: `\n` line
+/// endings and no trailing newline. Trailing spaces are kept, as day 5's crate
+/// drawing depends on them. Borrows when there is nothing to change.
+pub fn normalize(s: &str) -> Cow<'_, str> {
+    let s = if s.contains('\r') {
+        Cow::Owned(s.replace("\r\n", "\n"))
+    } else {
+        Cow::Borrowed(s)
+    };
+    match s {
+        Cow::Borrowed(s) => Cow::Borrowed(s.trim_end_matches('\n')),
+        Cow::Owned(mut s) => {
+            s.truncate(s.trim_end_matches('\n').len());
+            Cow::Owned(s)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn _normalize() {
+        assert_eq!("a\nb", normalize("a\nb"));
+        assert_eq!("a\nb", normalize("a\nb\n"));
+        assert_eq!("a\nb", normalize("a\r\nb\r\n\r\n"));
+        assert_eq!("a \n\nb ", normalize("a \r\n\r\nb \n"));
+        assert!(matches!(normalize("a\nb\n"), Cow::Borrowed(_)));
+    }
+}
@@ -0,0 +1,23 @@
+pub mod alloc;
+pub mod bench;
+mod cli;
+mod error;
+pub mod geom;
+pub mod grid;
+pub mod image;
+mod input;
+mod rng;
+mod simulation;
+mod solution;
+
+pub use cli::{
+    check, load_input, load_inputs, print_allocs, print_answer, print_answers, print_bench, render,
+    report, run, Format,
+};
+pub use error::ParseError;
+pub use geom::{Bounds, Vec2, Vec3, Vector};
+pub use grid::Grid;
+pub use input::normalize;
+pub use rng::Rng;
+pub use simulation::Simulation;
+pub use solution::{Answers, DynSolution, Maybe, Part, Solution};
@@ -0,0 +1,62 @@
+/// A puzzle answered by running a state machine step by step, so that tools
+/// can single-step it and observe the state in between.
+pub trait Simulation {
+    /// A copy of the state between two steps.
+    type State;
+
+    /// Advances by one step. Does nothing once the simulation is done.
+    fn step(&mut self);
+    fn is_done(&self) -> bool;
+    fn snapshot(&self) -> Self::State;
+
+    /// Steps until done and returns the number of steps taken.
+    fn run(&mut self) -> usize {
+        let mut steps = 0;
+        while !self.is_done() {
+            self.step();
+            steps += 1;
+        }
+        steps
+    }
+
+    /// Steps until done, returning a snapshot of the initial state and of the
+    /// state after every step, which can be replayed later.
+    fn record(&mut self) -> Vec<Self::State> {
+        let mut states = vec![self.snapshot()];
+        while !self.is_done() {
+            self.step();
+            states.push(self.snapshot());
+        }
+        states
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Countdown(u32);
+
+    impl Simulation for Countdown {
+        type State = u32;
+
+        fn step(&mut self) {
+            self.0 = self.0.saturating_sub(1);
+        }
+
+        fn is_done(&self) -> bool {
+            self.0 == 0
+        }
+
+        fn snapshot(&self) -> u32 {
+            self.0
+        }
+    }
+
+    #[test]
+    fn _run_and_record() {
+        assert_eq!(3, Countdown(3).run());
+        assert_eq!(vec![3, 2, 1, 0], Countdown(3).record());
+        assert_eq!(vec![0], Countdown(0).record());
+    }
+}
@@ -0,0 +1,177 @@
+use std::fmt::{self, Display};
+use std::time::{Duration, Instant};
+
+use crate::image::Rgb;
+use crate::{normalize, Grid, ParseError, Rng};
+
+/// A single day's puzzle: how to parse its input and answer both parts.
+///
+/// Implementors are unit structs (`pub struct Day7;`) so that they can also be
+/// used as trait objects through [`DynSolution`].
+pub trait Solution {
+    const DAY: u8;
+    /// The puzzle input bundled with the crate.
+    const INPUT: &'static str;
+    /// What to join several input files with to merge them into one input,
+    /// for days whose inputs can be combined. Other days take a single file.
+    const MERGE_SEPARATOR: Option<&'static str> = None;
+
+    type Input;
+    type Output1: Display;
+    type Output2: Display;
+
+    /// Parses input that has been through [`normalize`], so it uses `\n` line
+    /// endings and does not end in a newline.
+    fn parse(s: &str) -> Result<Self::Input, ParseError>;
+    fn answer_part1(input: &Self::Input) -> Self::Output1;
+    fn answer_part2(input: &Self::Input) -> Self::Output2;
+
+    /// Writes a random but well-formed input for stress-testing. What `size`
+    /// counts (lines, grid side, ...) is up to each day.
+    fn generate(rng: &mut Rng, size: usize) -> String;
+
+    /// Checks the assumptions the solvers make about the input beyond what
+    /// `parse` enforces, returning every violation found. `s` is the
+    /// normalised text `input` was parsed from, for pointing at violations.
+    fn check(_s: &str, _input: &Self::Input) -> Vec<ParseError> {
+        Vec::new()
+    }
+
+    /// Draws the puzzle as an image, for days that lend themselves to it.
+    fn render(_input: &Self::Input) -> Option<Grid<Rgb>> {
+        None
+    }
+
+    /// Describes the input in more detail than the answers do, for days with
+    /// something worth reporting.
+    fn report(_input: &Self::Input) -> Option<String> {
+        None
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn from_number(n: u8) -> Option<Part> {
+        match n {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+/// Rendered answers of a single run. A part that was not requested is `None`.
+#[derive(Clone, Debug, Default)]
+pub struct Answers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    pub parse_time: Duration,
+    /// Time spent answering the requested parts, including rendering them.
+    pub solve_time: Duration,
+}
+
+/// Object-safe view of a [`Solution`], so that days with different input and
+/// output types can live in the same list.
+pub trait DynSolution: Sync {
+    fn day(&self) -> u8;
+    fn input(&self) -> &'static str;
+    /// See [`Solution::MERGE_SEPARATOR`].
+    fn merge_separator(&self) -> Option<&'static str>;
+    /// Parses `input` after [`normalize`]-ing it and answers `part`, or both
+    /// parts if `part` is `None`.
+    fn solve(&self, input: &str, part: Option<Part>) -> Result<Answers, ParseError>;
+    /// Only parses `input`, after [`normalize`]-ing it.
+    fn try_parse(&self, input: &str) -> Result<(), ParseError>;
+    /// Generates an input of the given `size` from `seed`.
+    fn generate(&self, seed: u64, size: usize) -> String;
+    /// Parses `input` and checks it without solving. When parsing fails, the
+    /// parse error is the only problem returned.
+    fn check(&self, input: &str) -> Vec<ParseError>;
+    /// Parses `input` and draws it, or `None` if the day has no rendering.
+    fn render(&self, input: &str) -> Result<Option<Grid<Rgb>>, ParseError>;
+    /// Parses `input` and reports on it, or `None` if the day has no report.
+    fn report(&self, input: &str) -> Result<Option<String>, ParseError>;
+}
+
+impl<S: Solution + Sync> DynSolution for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn input(&self) -> &'static str {
+        S::INPUT
+    }
+
+    fn merge_separator(&self) -> Option<&'static str> {
+        S::MERGE_SEPARATOR
+    }
+
+    fn solve(&self, input: &str, part: Option<Part>) -> Result<Answers, ParseError> {
+        let input = normalize(input);
+        let start = Instant::now();
+        let input = S::parse(&input)?;
+        let parse_time = start.elapsed();
+
+        let start = Instant::now();
+        let wants = |p: Part| part.is_none_or(|part| part == p);
+        let part1 = wants(Part::One).then(|| S::answer_part1(&input).to_string());
+        let part2 = wants(Part::Two).then(|| S::answer_part2(&input).to_string());
+        let solve_time = start.elapsed();
+
+        Ok(Answers {
+            part1,
+            part2,
+            parse_time,
+            solve_time,
+        })
+    }
+
+    fn try_parse(&self, input: &str) -> Result<(), ParseError> {
+        S::parse(&normalize(input)).map(|_| ())
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> String {
+        S::generate(&mut Rng::new(seed), size)
+    }
+
+    fn check(&self, input: &str) -> Vec<ParseError> {
+        let input = normalize(input);
+        match S::parse(&input) {
+            Ok(parsed) => S::check(&input, &parsed),
+            Err(e) => vec![e],
+        }
+    }
+
+    fn render(&self, input: &str) -> Result<Option<Grid<Rgb>>, ParseError> {
+        S::parse(&normalize(input)).map(|input| S::render(&input))
+    }
+
+    fn report(&self, input: &str) -> Result<Option<String>, ParseError> {
+        S::parse(&normalize(input)).map(|input| S::report(&input))
+    }
+}
+
+/// An answer that might not exist, displayed as "no answer" when missing.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Maybe<T>(pub Option<T>);
+
+impl<T: Display> Display for Maybe<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Some(answer) => answer.fmt(f),
+            None => f.write_str("no answer"),
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use aoc_common::DynSolution;
+
+pub static DAYS: &[&dyn DynSolution] = &[
+    &day1::Day1,
+    &day2::Day2,
+    &day3::Day3,
+    &day4::Day4,
+    &day5::Day5,
+    &day6::Day6,
+    &day7::Day7,
+    &day8::Day8,
+    &day9::Day9,
+    &day10::Day10,
+    &day11::Day11,
+    &day12::Day12,
+    &day14::Day14,
+    &day18::Day18,
+];
+
+pub fn find(day: u8) -> Option<&'static dyn DynSolution> {
+    DAYS.iter().copied().find(|d| d.day() == day)
+}
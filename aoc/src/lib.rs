@@ -0,0 +1,3 @@
+//! The solved days, shared by the `aoc` binary and the example tests.
+
+pub mod days;
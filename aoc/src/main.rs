@@ -0,0 +1,261 @@
+use std::process::ExitCode;
+
+use aoc::days;
+use aoc_common::{check, load_inputs, print_answers, render, report, DynSolution, Format, Part};
+
+mod new_day;
+mod parallel;
+
+const USAGE: &str = "\
+Usage: aoc run <DAY|all> [--part <1|2>] [--input <PATH>]... [--format <text|json>]
+       aoc run <DAY> --render <PATH> [--input <PATH>]...
+       aoc run <DAY> --report [--input <PATH>]...
+       aoc run <DAY|all> --check [--input <PATH>]...
+       aoc run all --parallel [--jobs <N>] [--part <1|2>] [--format <text|json>]
+       aoc gen <DAY> --size <N> [--seed <SEED>]
+       aoc new-day <DAY> [--parser <nom|manual>] [--example <PATH>]";
+
+enum Selection {
+    All,
+    Day(u8),
+}
+
+struct RunArgs {
+    selection: Selection,
+    part: Option<Part>,
+    /// The input files of a single day, merged if the day takes several.
+    inputs: Vec<String>,
+    format: Format,
+    /// Number of worker threads when running all days in parallel.
+    jobs: Option<usize>,
+    /// Where to write the day's image, instead of printing its answers.
+    render: Option<String>,
+    /// Validate the input instead of solving.
+    check: bool,
+    /// Print the day's report on its input instead of its answers.
+    report: bool,
+}
+
+fn parse_run_args(args: &[String]) -> Result<RunArgs, String> {
+    let mut selection = None;
+    let mut part = None;
+    let mut inputs = Vec::new();
+    let mut format = Format::Text;
+    let mut parallel = false;
+    let mut jobs = None;
+    let mut render = None;
+    let mut check = false;
+    let mut report = false;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => {
+                let n = args.next().ok_or("--part needs a value")?;
+                let p = n
+                    .parse::<u8>()
+                    .ok()
+                    .and_then(Part::from_number)
+                    .ok_or_else(|| format!("invalid part '{}'", n))?;
+                part = Some(p);
+            }
+            "--input" => {
+                let path = args.next().ok_or("--input needs a value")?;
+                inputs.push(path.clone());
+            }
+            "--format" => {
+                let name = args.next().ok_or("--format needs a value")?;
+                format =
+                    Format::from_name(name).ok_or_else(|| format!("invalid format '{}'", name))?;
+            }
+            "--render" => {
+                let path = args.next().ok_or("--render needs a value")?;
+                render = Some(path.clone());
+            }
+            "--check" => check = true,
+            "--report" => report = true,
+            "--parallel" => parallel = true,
+            "--jobs" => {
+                let n = args.next().ok_or("--jobs needs a value")?;
+                let n = n
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|n| *n > 0)
+                    .ok_or_else(|| format!("invalid job count '{}'", n))?;
+                jobs = Some(n);
+            }
+            "all" => selection = Some(Selection::All),
+            option if option.starts_with('-') => {
+                return Err(format!("unknown option '{}'", option));
+            }
+            day => {
+                let day = day
+                    .parse::<u8>()
+                    .map_err(|_| format!("invalid day '{}'", day))?;
+                selection = Some(Selection::Day(day));
+            }
+        }
+    }
+    let selection = selection.ok_or("missing day")?;
+    if !inputs.is_empty() && matches!(selection, Selection::All) {
+        return Err("--input can only be used with a single day".to_string());
+    }
+    if render.is_some() && matches!(selection, Selection::All) {
+        return Err("--render can only be used with a single day".to_string());
+    }
+    if report && matches!(selection, Selection::All) {
+        return Err("--report can only be used with a single day".to_string());
+    }
+    if (parallel || jobs.is_some()) && !matches!(selection, Selection::All) {
+        return Err("--parallel can only be used with all".to_string());
+    }
+    let jobs = if parallel || jobs.is_some() {
+        let available = std::thread::available_parallelism().map_or(1, |n| n.get());
+        Some(jobs.unwrap_or(available))
+    } else {
+        None
+    };
+    Ok(RunArgs {
+        selection,
+        part,
+        inputs,
+        format,
+        jobs,
+        render,
+        check,
+        report,
+    })
+}
+
+fn run_day(day: &dyn DynSolution, input: &str, args: &RunArgs) -> Result<(), String> {
+    if args.format == Format::Text {
+        println!("Day {}", day.day());
+    }
+    let answers = day
+        .solve(input, args.part)
+        .map_err(|e| format!("day {} input:\n{}", day.day(), e.report()))?;
+    print_answers(day.day(), &answers, args.format);
+    Ok(())
+}
+
+/// Checks the selected days' inputs, failing if any has problems.
+fn run_check(args: &RunArgs) -> Result<(), String> {
+    let passed = match args.selection {
+        Selection::All => {
+            days::DAYS
+                .iter()
+                .filter(|day| !check(**day, day.input()))
+                .count()
+                == 0
+        }
+        Selection::Day(n) => {
+            let day = days::find(n).ok_or_else(|| format!("day {} is not solved", n))?;
+            let input = load_inputs(day, &args.inputs)?;
+            check(day, &input)
+        }
+    };
+    if passed {
+        Ok(())
+    } else {
+        Err("input check failed".to_string())
+    }
+}
+
+fn run_parallel(threads: usize, args: &RunArgs) -> Result<(), String> {
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![Part::One, Part::Two],
+    };
+    let summary = parallel::solve_all(days::DAYS, &parts, threads)?;
+    match args.format {
+        Format::Text => println!("{}", parallel::summary_table(&summary)),
+        Format::Json => {
+            for result in &summary.days {
+                print_answers(result.day, &result.answers(), Format::Json);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn run(args: RunArgs) -> Result<(), String> {
+    if args.check {
+        return run_check(&args);
+    }
+    if let Some(threads) = args.jobs {
+        return run_parallel(threads, &args);
+    }
+    match args.selection {
+        Selection::All => {
+            for day in days::DAYS {
+                run_day(*day, day.input(), &args)?;
+            }
+        }
+        Selection::Day(n) => {
+            let day = days::find(n).ok_or_else(|| format!("day {} is not solved", n))?;
+            let input = load_inputs(day, &args.inputs)?;
+            match &args.render {
+                Some(path) => render(day, &input, path)?,
+                None if args.report => report(day, &input)?,
+                None => run_day(day, &input, &args)?,
+            }
+        }
+    }
+    Ok(())
+}
+
+struct GenArgs {
+    day: u8,
+    size: usize,
+    seed: u64,
+}
+
+fn parse_gen_args(args: &[String]) -> Result<GenArgs, String> {
+    let mut day = None;
+    let mut size = None;
+    let mut seed = 0;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--size" => {
+                let n = args.next().ok_or("--size needs a value")?;
+                size = Some(n.parse().map_err(|_| format!("invalid size '{}'", n))?);
+            }
+            "--seed" => {
+                let n = args.next().ok_or("--seed needs a value")?;
+                seed = n.parse().map_err(|_| format!("invalid seed '{}'", n))?;
+            }
+            n => {
+                day = Some(n.parse().map_err(|_| format!("invalid day '{}'", n))?);
+            }
+        }
+    }
+    Ok(GenArgs {
+        day: day.ok_or("missing day")?,
+        size: size.ok_or("missing --size")?,
+        seed,
+    })
+}
+
+/// Prints a generated input, without a trailing newline like the bundled ones.
+fn generate(args: GenArgs) -> Result<(), String> {
+    let day = days::find(args.day).ok_or_else(|| format!("day {} is not solved", args.day))?;
+    print!("{}", day.generate(args.seed, args.size));
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = match args.first().map(String::as_str) {
+        Some("run") => parse_run_args(&args[1..]).and_then(run),
+        Some("gen") => parse_gen_args(&args[1..]).and_then(generate),
+        Some("new-day") => new_day::parse_new_day_args(&args[1..]).and_then(new_day::new_day),
+        _ => Err(USAGE.to_string()),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
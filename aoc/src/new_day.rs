@@ -0,0 +1,216 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const CARGO_TOML: &str = include_str!("../../_template/Cargo.toml");
+const MAIN_RS: &str = include_str!("../../_template/src/main.rs");
+const LIB_NOM_RS: &str = include_str!("../../_template/src/lib_nom.rs");
+const LIB_MANUAL_RS: &str = include_str!("../../_template/src/lib_manual.rs");
+const SOLUTION_RS: &str = include_str!("../../_template/src/solution.rs");
+const ANSWERS_TOML: &str = include_str!("../../_template/answers.toml");
+const EXAMPLE_TOML: &str = include_str!("../../_template/examples/example.toml");
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParserStyle {
+    Nom,
+    Manual,
+}
+
+pub struct NewDayArgs {
+    pub day: u8,
+    pub parser: ParserStyle,
+    pub example: Option<String>,
+}
+
+pub fn parse_new_day_args(args: &[String]) -> Result<NewDayArgs, String> {
+    let mut day = None;
+    let mut parser = ParserStyle::Manual;
+    let mut example = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--parser" => {
+                let style = args.next().ok_or("--parser needs a value")?;
+                parser = match style.as_str() {
+                    "nom" => ParserStyle::Nom,
+                    "manual" => ParserStyle::Manual,
+                    _ => return Err(format!("invalid parser style '{}'", style)),
+                };
+            }
+            "--example" => {
+                let path = args.next().ok_or("--example needs a value")?;
+                example = Some(path.clone());
+            }
+            n => {
+                let n = n
+                    .parse::<u8>()
+                    .ok()
+                    .filter(|n| (1..=25).contains(n))
+                    .ok_or_else(|| format!("invalid day '{}'", n))?;
+                day = Some(n);
+            }
+        }
+    }
+    Ok(NewDayArgs {
+        day: day.ok_or("missing day")?,
+        parser,
+        example,
+    })
+}
+
+/// Finds the workspace root by walking up from the current directory.
+fn workspace_root() -> Result<PathBuf, String> {
+    let cwd = std::env::current_dir().map_err(|e| e.to_string())?;
+    cwd.ancestors()
+        .find(|dir| {
+            fs::read_to_string(dir.join("Cargo.toml")).is_ok_and(|s| s.contains("[workspace]"))
+        })
+        .map(Path::to_path_buf)
+        .ok_or_else(|| "not inside a cargo workspace".to_string())
+}
+
+/// Adds `name` to the `members` list of a workspace manifest, keeping the days
+/// in numeric order.
+fn add_member(manifest: &str, name: &str, day: u8) -> Result<String, String> {
+    let start = manifest
+        .find("members = [")
+        .ok_or("workspace manifest has no members list")?;
+    let end = start
+        + manifest[start..]
+            .find(']')
+            .ok_or("unterminated members list")?;
+    let entry = format!("\"{}\"", name);
+    if manifest[start..end].contains(&entry) {
+        return Err(format!("{} is already a workspace member", name));
+    }
+
+    let later_day = manifest[start..end].lines().find_map(|line| {
+        let member = line.trim().trim_end_matches(',').trim_matches('"');
+        let n = member.strip_prefix("day")?.parse::<u8>().ok()?;
+        (n > day).then_some(line)
+    });
+    let at = match later_day {
+        Some(line) => line.as_ptr() as usize - manifest.as_ptr() as usize,
+        None => end,
+    };
+    Ok(format!(
+        "{}    {},\n{}",
+        &manifest[..at],
+        entry,
+        &manifest[at..]
+    ))
+}
+
+fn render(template: &str, name: &str, day: u8) -> String {
+    template
+        .replace("TEMPLATE_NAME", name)
+        .replace("TEMPLATE_STRUCT", &format!("Day{}", day))
+        .replace("TEMPLATE_DAY", &day.to_string())
+}
+
+/// Creates the `dayN` crate inside the workspace at `root` and registers it as
+/// a workspace member.
+fn create(root: &Path, day: u8, parser: ParserStyle, example: &str) -> Result<PathBuf, String> {
+    let name = format!("day{}", day);
+    let dir = root.join(&name);
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+    let manifest_path = root.join("Cargo.toml");
+    let manifest = fs::read_to_string(&manifest_path).map_err(|e| e.to_string())?;
+    let manifest = add_member(&manifest, &name, day)?;
+
+    let (lib, dependencies) = match parser {
+        ParserStyle::Nom => (
+            LIB_NOM_RS,
+            "aoc-common = { path = \"../aoc-common\", features = [\"nom\"] }\nnom = \"7.1\"",
+        ),
+        ParserStyle::Manual => (LIB_MANUAL_RS, "aoc-common = { path = \"../aoc-common\" }"),
+    };
+    let files = [
+        (
+            "Cargo.toml",
+            CARGO_TOML.replace("TEMPLATE_DEPENDENCIES", dependencies),
+        ),
+        ("answers.toml", ANSWERS_TOML.to_string()),
+        ("src/main.rs", MAIN_RS.to_string()),
+        (
+            "src/lib.rs",
+            lib.replace("TEMPLATE_SOLUTION\n", SOLUTION_RS),
+        ),
+        ("src/inputs", String::new()),
+        ("examples/example.txt", example.to_string()),
+        ("examples/example.toml", EXAMPLE_TOML.to_string()),
+    ];
+
+    let write = |path: &Path, contents: &str| {
+        fs::create_dir_all(path.parent().unwrap())
+            .and_then(|_| fs::write(path, contents))
+            .map_err(|e| format!("failed to write {}: {}", path.display(), e))
+    };
+    for (path, contents) in &files {
+        write(&dir.join(path), &render(contents, &name, day))?;
+    }
+    write(&manifest_path, &manifest)?;
+    Ok(dir)
+}
+
+pub fn new_day(args: NewDayArgs) -> Result<(), String> {
+    let example = match &args.example {
+        Some(path) => fs::read_to_string(path)
+            .map_err(|e| format!("failed to read example {}: {}", path, e))?,
+        None => String::new(),
+    };
+    let dir = create(&workspace_root()?, args.day, args.parser, &example)?;
+    println!("Created {}", dir.display());
+    println!(
+        "Add `&day{0}::Day{0}` to aoc/src/days.rs once it is solved.",
+        args.day
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str =
+        "[workspace]\nmembers = [\n    \"aoc\",\n    \"day1\",\n    \"day14\",\n]\n";
+
+    #[test]
+    fn _add_member() {
+        assert_eq!(
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"day1\",\n    \"day13\",\n    \"day14\",\n]\n",
+            add_member(MANIFEST, "day13", 13).unwrap()
+        );
+        assert_eq!(
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"day1\",\n    \"day14\",\n    \"day15\",\n]\n",
+            add_member(MANIFEST, "day15", 15).unwrap()
+        );
+        assert!(add_member(MANIFEST, "day14", 14).is_err());
+    }
+
+    #[test]
+    fn _create() {
+        let root = std::env::temp_dir().join("aoc_new_day");
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        fs::write(root.join("Cargo.toml"), MANIFEST).unwrap();
+
+        create(&root, 13, ParserStyle::Nom, "1\n2\n").unwrap();
+        let lib = fs::read_to_string(root.join("day13/src/lib.rs")).unwrap();
+        assert!(lib.contains("pub struct Day13;"));
+        assert!(lib.contains("const DAY: u8 = 13;"));
+        let cargo = fs::read_to_string(root.join("day13/Cargo.toml")).unwrap();
+        assert!(cargo.contains("name = \"day13\""));
+        assert!(cargo.contains("nom = \"7.1\""));
+        assert_eq!(
+            "",
+            fs::read_to_string(root.join("day13/src/inputs")).unwrap()
+        );
+        let manifest = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+        assert!(manifest.contains("\"day13\","));
+        assert!(create(&root, 13, ParserStyle::Manual, "").is_err());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
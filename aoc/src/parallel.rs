@@ -0,0 +1,243 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc_common::{Answers, DynSolution, Part};
+
+/// Answers of one day, with each part solved by its own job.
+pub struct DayResult {
+    pub day: u8,
+    pub part1: Answers,
+    pub part2: Answers,
+}
+
+impl DayResult {
+    /// Parse and solve time of both jobs together.
+    pub fn time(&self) -> Duration {
+        [&self.part1, &self.part2]
+            .iter()
+            .map(|a| a.parse_time + a.solve_time)
+            .sum()
+    }
+
+    /// Both parts as a single run, with the timings of both jobs summed.
+    pub fn answers(&self) -> Answers {
+        Answers {
+            part1: self.part1.part1.clone(),
+            part2: self.part2.part2.clone(),
+            parse_time: self.part1.parse_time + self.part2.parse_time,
+            solve_time: self.part1.solve_time + self.part2.solve_time,
+        }
+    }
+}
+
+pub struct Summary {
+    pub days: Vec<DayResult>,
+    pub threads: usize,
+    pub wall_time: Duration,
+}
+
+/// Solves `parts` of every day on `threads` worker threads. Each (day, part)
+/// pair is a separate job, so that a slow part doesn't hold up the other
+/// part of the same day. Results are in the order of `days`.
+pub fn solve_all(
+    days: &[&'static dyn DynSolution],
+    parts: &[Part],
+    threads: usize,
+) -> Result<Summary, String> {
+    let jobs: Vec<_> = days
+        .iter()
+        .flat_map(|&day| parts.iter().map(move |&part| (day, part)))
+        .collect();
+    let threads = threads.clamp(1, jobs.len().max(1));
+    let next = AtomicUsize::new(0);
+
+    let start = Instant::now();
+    let mut done = thread::scope(|s| {
+        let workers: Vec<_> = (0..threads)
+            .map(|_| {
+                s.spawn(|| {
+                    let mut done = Vec::new();
+                    loop {
+                        let i = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(&(day, part)) = jobs.get(i) else {
+                            return done;
+                        };
+                        done.push((i, day.solve(day.input(), Some(part))));
+                    }
+                })
+            })
+            .collect();
+        let mut done = Vec::new();
+        for worker in workers {
+            done.extend(worker.join().expect("worker thread panicked"));
+        }
+        done
+    });
+    let wall_time = start.elapsed();
+
+    done.sort_unstable_by_key(|(i, _)| *i);
+    let mut results = Vec::with_capacity(done.len());
+    for (i, result) in done {
+        let day = jobs[i].0.day();
+        results.push(result.map_err(|e| format!("day {} input:\n{}", day, e.report()))?);
+    }
+    let mut results = results.into_iter();
+    let days = days
+        .iter()
+        .map(|day| {
+            let mut part = |p: Part| {
+                if parts.contains(&p) {
+                    results.next().unwrap()
+                } else {
+                    Answers::default()
+                }
+            };
+            let part1 = part(Part::One);
+            let part2 = part(Part::Two);
+            DayResult {
+                day: day.day(),
+                part1,
+                part2,
+            }
+        })
+        .collect();
+    Ok(Summary {
+        days,
+        threads,
+        wall_time,
+    })
+}
+
+/// Renders the summary as a table with one row per day and a total row.
+/// Multi-line answers don't fit in a cell, so they are written out below the
+/// table instead.
+pub fn summary_table(summary: &Summary) -> String {
+    let mut rows = vec![[
+        "Day".to_string(),
+        "Part 1".to_string(),
+        "Part 2".to_string(),
+        "Time".to_string(),
+    ]];
+    let mut below = Vec::new();
+    for result in &summary.days {
+        let mut cell = |part: Part, answer: &Option<String>| match answer {
+            Some(answer) if answer.contains('\n') => {
+                below.push(format!(
+                    "Day {} part {} =\n{}",
+                    result.day,
+                    part.number(),
+                    answer
+                ));
+                "(below)".to_string()
+            }
+            Some(answer) => answer.clone(),
+            None => String::new(),
+        };
+        rows.push([
+            result.day.to_string(),
+            cell(Part::One, &result.part1.part1),
+            cell(Part::Two, &result.part2.part2),
+            format!("{:.1?}", result.time()),
+        ]);
+    }
+    let total: Duration = summary.days.iter().map(DayResult::time).sum();
+    rows.push([
+        "Total".to_string(),
+        String::new(),
+        String::new(),
+        format!("{:.1?}", total),
+    ]);
+
+    let mut widths = [0; 4];
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let mut lines: Vec<String> = rows
+        .iter()
+        .map(|[day, part1, part2, time]| {
+            let line = format!(
+                "{:<w0$}  {:<w1$}  {:<w2$}  {:>w3$}",
+                day,
+                part1,
+                part2,
+                time,
+                w0 = widths[0],
+                w1 = widths[1],
+                w2 = widths[2],
+                w3 = widths[3],
+            );
+            line.trim_end().to_string()
+        })
+        .collect();
+    lines.push(format!(
+        "Wall-clock {:.1?} on {} thread{}",
+        summary.wall_time,
+        summary.threads,
+        if summary.threads == 1 { "" } else { "s" }
+    ));
+    lines.extend(below.into_iter().map(|answer| format!("\n{}", answer)));
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn _solve_all() {
+        let days = [aoc::days::find(1).unwrap(), aoc::days::find(10).unwrap()];
+        let summary = solve_all(&days, &[Part::One, Part::Two], 4).unwrap();
+        assert_eq!(4, summary.threads);
+        let day1 = &summary.days[0];
+        assert_eq!(1, day1.day);
+        assert_eq!(Some("74711".to_string()), day1.part1.part1);
+        assert_eq!(None, day1.part1.part2);
+        assert_eq!(Some("209481".to_string()), day1.part2.part2);
+
+        let summary = solve_all(&days, &[Part::Two], 1).unwrap();
+        assert_eq!(None, summary.days[1].part1.part1);
+        assert!(summary.days[1].part2.part2.is_some());
+    }
+
+    #[test]
+    fn _summary_table() {
+        let answers = |part1: Option<&str>, part2: Option<&str>, ms| Answers {
+            part1: part1.map(str::to_string),
+            part2: part2.map(str::to_string),
+            solve_time: Duration::from_millis(ms),
+            ..Answers::default()
+        };
+        let summary = Summary {
+            days: vec![
+                DayResult {
+                    day: 1,
+                    part1: answers(Some("24000"), None, 1),
+                    part2: answers(None, Some("45000"), 2),
+                },
+                DayResult {
+                    day: 10,
+                    part1: answers(Some("13140"), None, 10),
+                    part2: answers(None, Some("#.\n.#"), 20),
+                },
+            ],
+            threads: 2,
+            wall_time: Duration::from_millis(20),
+        };
+        assert_eq!(
+            "\
+Day    Part 1  Part 2     Time
+1      24000   45000     3.0ms
+10     13140   (below)  30.0ms
+Total                   33.0ms
+Wall-clock 20.0ms on 2 threads
+
+Day 10 part 2 =
+#.
+.#",
+            summary_table(&summary)
+        );
+    }
+}
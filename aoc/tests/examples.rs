@@ -0,0 +1,122 @@
+//! Runs every day against its recorded answers.
+//!
+//! Each `dayN/examples/NAME.txt` becomes its own tests, checked against the
+//! answers in the sidecar `dayN/examples/NAME.toml`. Every example is fed in
+//! four ways: with LF or CRLF line endings, each with and without a final
+//! newline. The bundled input is checked against `dayN/answers.toml` as the
+//! `dayN::answers` test, and checked for problems as the `dayN::check` test.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc::days::DAYS;
+use aoc_common::DynSolution;
+use libtest_mimic::{Arguments, Failed, Trial};
+
+fn day_dir(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day{}", day))
+}
+
+/// Reads the `part1` and `part2` answers recorded in the TOML file at `path`.
+/// A part without a recorded answer is `None` and is not checked.
+fn expected_answers(path: &Path) -> Result<(Option<String>, Option<String>), Failed> {
+    let s = fs::read_to_string(path)
+        .map_err(|e| format!("failed to read {}: {}", path.display(), e))?;
+    let table: toml::Table = s
+        .parse()
+        .map_err(|e| format!("failed to parse {}: {}", path.display(), e))?;
+    let part = |key: &str| table.get(key).and_then(|v| v.as_str()).map(str::to_string);
+    Ok((part("part1"), part("part2")))
+}
+
+fn check(day: &dyn DynSolution, input: &str, expected: &Path) -> Result<(), Failed> {
+    let (part1, part2) = expected_answers(expected)?;
+    let answers = day.solve(input, None).map_err(|e| e.to_string())?;
+    let mut failures = Vec::new();
+    if part1.is_some() && part1 != answers.part1 {
+        failures.push(format!(
+            "part 1: expected {:?}, got {:?}",
+            part1, answers.part1
+        ));
+    }
+    if part2.is_some() && part2 != answers.part2 {
+        failures.push(format!(
+            "part 2: expected {:?}, got {:?}",
+            part2, answers.part2
+        ));
+    }
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(failures.join("\n").into())
+    }
+}
+
+/// The line ending and final newline variants of an example, by name.
+fn variants(example: &str) -> [(&'static str, String); 4] {
+    let lf = example
+        .replace("\r\n", "\n")
+        .trim_end_matches('\n')
+        .to_string();
+    let crlf = lf.replace('\n', "\r\n");
+    [
+        ("lf", lf.clone()),
+        ("lf_final_newline", lf + "\n"),
+        ("crlf", crlf.clone()),
+        ("crlf_final_newline", crlf + "\r\n"),
+    ]
+}
+
+fn example_trials(day: &'static dyn DynSolution) -> Vec<Trial> {
+    let Ok(entries) = fs::read_dir(day_dir(day.day()).join("examples")) else {
+        return Vec::new();
+    };
+    let mut paths: Vec<_> = entries
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| p.extension().is_some_and(|ext| ext == "txt"))
+        .collect();
+    paths.sort();
+    let mut trials = Vec::new();
+    for path in paths {
+        let stem = path.file_stem().unwrap().to_string_lossy().into_owned();
+        let expected = path.with_extension("toml");
+        let example = match fs::read_to_string(&path) {
+            Ok(example) => example,
+            Err(e) => {
+                let message = format!("failed to read {}: {}", path.display(), e);
+                let name = format!("day{}::examples::{}", day.day(), stem);
+                trials.push(Trial::test(name, move || Err(message.into())));
+                continue;
+            }
+        };
+        for (variant, input) in variants(&example) {
+            let name = format!("day{}::examples::{}::{}", day.day(), stem, variant);
+            let expected = expected.clone();
+            trials.push(Trial::test(name, move || check(day, &input, &expected)));
+        }
+    }
+    trials
+}
+
+fn main() {
+    let args = Arguments::from_args();
+    let mut trials = Vec::new();
+    for &day in DAYS {
+        trials.push(Trial::test(
+            format!("day{}::answers", day.day()),
+            move || check(day, day.input(), &day_dir(day.day()).join("answers.toml")),
+        ));
+        trials.push(Trial::test(format!("day{}::check", day.day()), move || {
+            let problems: Vec<_> = day.check(day.input()).iter().map(|e| e.report()).collect();
+            if problems.is_empty() {
+                Ok(())
+            } else {
+                Err(problems.join("\n\n").into())
+            }
+        }));
+        trials.extend(example_trials(day));
+    }
+    libtest_mimic::run(&args, trials).exit();
+}
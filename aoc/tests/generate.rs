@@ -0,0 +1,12 @@
+use aoc::days::DAYS;
+
+#[test]
+fn _generated_inputs_solve() {
+    for day in DAYS {
+        let input = day.generate(1, 50);
+        assert_eq!(input, day.generate(1, 50), "day {}", day.day());
+        if let Err(e) = day.solve(&input, None) {
+            panic!("day {}: {}\n{}", day.day(), e, input);
+        }
+    }
+}
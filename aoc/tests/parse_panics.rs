@@ -0,0 +1,91 @@
+//! Every day's `parse` must reject malformed input with an error, never panic,
+//! and the solvers must not panic on any input `parse` accepts. `fuzz/` runs
+//! the same check under libFuzzer.
+
+use aoc::days::DAYS;
+use proptest::prelude::*;
+
+/// Fragments of the various puzzle formats, so that generated inputs get past
+/// the first few bytes of each parser.
+const TOKENS: &[&str] = &[
+    "\n",
+    "\n\n",
+    " ",
+    ",",
+    "-",
+    "->",
+    " -> ",
+    ":",
+    "[",
+    "]",
+    "   ",
+    "$ cd ",
+    "$ cd ..",
+    "$ cd /",
+    "$ ls\n",
+    "dir ",
+    "move ",
+    " from ",
+    " to ",
+    "addx ",
+    "noop",
+    "Monkey ",
+    ":\n",
+    "  Starting items: ",
+    ", ",
+    "  Operation: new = ",
+    "old",
+    " * ",
+    " + ",
+    "  Test: divisible by ",
+    "    If true: throw to monkey ",
+    "    If false: throw to monkey ",
+    "A",
+    "B",
+    "C",
+    "X",
+    "Y",
+    "Z",
+    "L",
+    "R",
+    "U",
+    "D",
+    "S",
+    "E",
+    "a",
+    "z",
+    "0",
+    "1",
+    "9",
+    "12",
+    "255",
+    "256",
+    "-1",
+    "99999999999",
+    "é",
+];
+
+fn token_soup() -> impl Strategy<Value = String> {
+    prop::collection::vec(prop::sample::select(TOKENS), 0..60).prop_map(|t| t.concat())
+}
+
+fn assert_no_panic(input: &str) {
+    for day in DAYS {
+        // A panic fails the test; an error is the expected outcome.
+        if day.try_parse(input).is_ok() {
+            let _ = day.solve(input, None);
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn _arbitrary_text(input in "\\PC*") {
+        assert_no_panic(&input);
+    }
+
+    #[test]
+    fn _token_soup(input in token_soup()) {
+        assert_no_panic(&input);
+    }
+}
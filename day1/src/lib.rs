@@ -0,0 +1,713 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fmt;
+use std::io::{self, BufRead};
+
+use aoc_common::{ParseError, Rng, Solution};
+
+/// The calories of one elf's snacks, and the elf's name if a `# name` line
+/// gives it one.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Elf {
+    pub name: Option<String>,
+    pub items: Vec<u32>,
+}
+
+pub type Input = Vec<Elf>;
+
+/// What one line of a calorie list holds.
+enum Line<'a> {
+    Blank,
+    Name(&'a str),
+    Calories(u32),
+}
+
+/// Classifies `line`, which has no line ending, or says what is wrong with it.
+fn classify(line: &str) -> Result<Line<'_>, &'static str> {
+    if line.is_empty() {
+        Ok(Line::Blank)
+    } else if let Some(name) = line.strip_prefix('#') {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err("expected a name after '#'");
+        }
+        Ok(Line::Name(name))
+    } else {
+        line.parse()
+            .map(Line::Calories)
+            .map_err(|_| "expected a calorie count")
+    }
+}
+
+/// Parses groups of calorie counts separated by runs of blank lines. A
+/// `# name` line names the elf whose counts follow, starting a new group if
+/// need be. Accepts `\r\n` line endings, and [`elf_totals`] accepts exactly
+/// the same lists.
+pub fn parse(s: &str) -> Result<Input, ParseError> {
+    let mut elves = Vec::new();
+    let mut elf = Elf::default();
+    // The line naming `elf`, until a count follows it.
+    let mut name_line: Option<&str> = None;
+    for line in s.split('\n') {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        match classify(line).map_err(|message| ParseError::at(s, line, message))? {
+            Line::Calories(n) => {
+                elf.items.push(n);
+                name_line = None;
+            }
+            Line::Name(name) => {
+                if name_line.is_some() {
+                    return Err(ParseError::at(s, line, "expected a calorie count"));
+                }
+                if !elf.items.is_empty() {
+                    elves.push(std::mem::take(&mut elf));
+                }
+                elf.name = Some(name.to_string());
+                name_line = Some(line);
+            }
+            Line::Blank => {
+                if let Some(name_line) = name_line {
+                    return Err(missing_count(s, name_line));
+                }
+                if !elf.items.is_empty() {
+                    elves.push(std::mem::take(&mut elf));
+                }
+            }
+        }
+    }
+    if let Some(name_line) = name_line {
+        return Err(missing_count(s, name_line));
+    }
+    if !elf.items.is_empty() {
+        elves.push(elf);
+    }
+    Ok(elves)
+}
+
+/// The error for a name not followed by any count, pointing past the name.
+fn missing_count(input: &str, name_line: &str) -> ParseError {
+    let end = &name_line[name_line.len()..];
+    ParseError::at(input, end, "expected a calorie count")
+}
+
+/// Why reading a calorie list from a stream failed.
+#[derive(Debug)]
+pub enum ReadError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadError::Io(e) => write!(f, "failed to read input: {}", e),
+            ReadError::Parse(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for ReadError {}
+
+impl From<io::Error> for ReadError {
+    fn from(e: io::Error) -> Self {
+        ReadError::Io(e)
+    }
+}
+
+/// Each elf's calorie total, read line by line from a calorie list. Only the
+/// running total of the current elf and the current line are held, so lists
+/// of any size are read in constant memory. Reads the same lists as [`parse`]
+/// and fails the same way, names aside, which are checked but dropped.
+pub struct ElfTotals<R> {
+    reader: R,
+    line: String,
+    line_number: usize,
+    /// The number and text of the line naming the current elf, until a count
+    /// follows it.
+    name_line: Option<(usize, String)>,
+}
+
+pub fn elf_totals<R: BufRead>(reader: R) -> ElfTotals<R> {
+    ElfTotals {
+        reader,
+        line: String::new(),
+        line_number: 0,
+        name_line: None,
+    }
+}
+
+/// A parse error at `at` in `line`, the line being all the input there is to
+/// point into.
+fn line_error(line_number: usize, line: &str, at: &str, message: &str) -> ReadError {
+    ReadError::Parse(ParseError {
+        line: line_number,
+        ..ParseError::at(line, at, message)
+    })
+}
+
+impl<R: BufRead> ElfTotals<R> {
+    fn missing_count(&mut self) -> Option<ReadError> {
+        let (number, line) = self.name_line.take()?;
+        Some(line_error(
+            number,
+            &line,
+            &line[line.len()..],
+            "expected a calorie count",
+        ))
+    }
+}
+
+impl<R: BufRead> Iterator for ElfTotals<R> {
+    type Item = Result<u64, ReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut total: Option<u64> = None;
+        loop {
+            self.line.clear();
+            match self.reader.read_line(&mut self.line) {
+                Ok(0) => {
+                    if let Some(e) = self.missing_count() {
+                        return Some(Err(e));
+                    }
+                    return total.map(Ok);
+                }
+                Ok(_) => self.line_number += 1,
+                Err(e) => return Some(Err(e.into())),
+            }
+            let line = self.line.trim_end_matches('\n');
+            let line = line.strip_suffix('\r').unwrap_or(line);
+            let kind = match classify(line) {
+                Ok(kind) => kind,
+                Err(message) => {
+                    return Some(Err(line_error(self.line_number, line, line, message)));
+                }
+            };
+            match kind {
+                Line::Calories(n) => {
+                    total = Some(total.unwrap_or(0) + n as u64);
+                    self.name_line = None;
+                }
+                Line::Name(_) => {
+                    if self.name_line.is_some() {
+                        let e =
+                            line_error(self.line_number, line, line, "expected a calorie count");
+                        return Some(Err(e));
+                    }
+                    self.name_line = Some((self.line_number, line.to_string()));
+                    if total.is_some() {
+                        return total.map(Ok);
+                    }
+                }
+                Line::Blank => {
+                    if let Some(e) = self.missing_count() {
+                        return Some(Err(e));
+                    }
+                    if total.is_some() {
+                        return total.map(Ok);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The `k` largest items seen so far, in a min-heap of at most `k` entries.
+struct TopK<T> {
+    k: usize,
+    heap: BinaryHeap<Reverse<T>>,
+}
+
+impl<T: Ord> TopK<T> {
+    fn new(k: usize) -> Self {
+        TopK {
+            k,
+            heap: BinaryHeap::with_capacity(k + 1),
+        }
+    }
+
+    fn push(&mut self, item: T) {
+        self.heap.push(Reverse(item));
+        if self.heap.len() > self.k {
+            self.heap.pop();
+        }
+    }
+
+    /// Largest first.
+    fn into_vec(self) -> Vec<T> {
+        // `Reverse` makes the ascending order of the heap descending.
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|r| r.0)
+            .collect()
+    }
+}
+
+/// The `k` largest of `items`, largest first, or all of them if there are
+/// fewer than `k`.
+pub fn top_k<T: Ord>(items: impl IntoIterator<Item = T>, k: usize) -> Vec<T> {
+    let mut top = TopK::new(k);
+    for item in items {
+        top.push(item);
+    }
+    top.into_vec()
+}
+
+/// [`top_k`] of the elf totals in a calorie list read from `reader`, in
+/// constant memory.
+///
+/// ```
+/// let list = "1000\n2000\n\n4000\n\n5000\n6000\n";
+/// let top = day1::top_k_from_reader(list.as_bytes(), 2).unwrap();
+/// assert_eq!(vec![11000, 4000], top);
+/// ```
+pub fn top_k_from_reader(reader: impl BufRead, k: usize) -> Result<Vec<u64>, ReadError> {
+    let mut top = TopK::new(k);
+    for total in elf_totals(reader) {
+        top.push(total?);
+    }
+    Ok(top.into_vec())
+}
+
+fn totals(inputs: &Input) -> impl Iterator<Item = u64> + '_ {
+    inputs
+        .iter()
+        .map(|elf| elf.items.iter().map(|&n| n as u64).sum())
+}
+
+/// Calories carried by one or more elves, and who they are when any of them
+/// is named. Displays as the calories, followed by the elves in parentheses.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Carried {
+    pub calories: u64,
+    /// Names, or `elf N` for unnamed elves, N numbering the elves from 1 in
+    /// input order, as in [`report`].
+    pub elves: Vec<String>,
+}
+
+impl fmt::Display for Carried {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.calories)?;
+        if !self.elves.is_empty() {
+            write!(f, " ({})", self.elves.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// What the `k` elves carrying the most carry between them.
+pub fn top_elves(inputs: &Input, k: usize) -> Carried {
+    // Ties go to the elf that comes first.
+    let top = top_k(totals(inputs).zip((0..).map(Reverse)), k);
+    let named = top.iter().any(|(_, i)| inputs[i.0].name.is_some());
+    let elves = if named {
+        let name = |i: usize| inputs[i].name.clone();
+        let names = top
+            .iter()
+            .map(|(_, i)| name(i.0).unwrap_or(format!("elf {}", i.0 + 1)));
+        names.collect()
+    } else {
+        Vec::new()
+    };
+    Carried {
+        calories: top.iter().map(|(total, _)| total).sum(),
+        elves,
+    }
+}
+
+pub fn answer_part1(inputs: &Input) -> Carried {
+    top_elves(inputs, 1)
+}
+
+pub fn answer_part2(inputs: &Input) -> Carried {
+    top_elves(inputs, 3)
+}
+
+/// One elf's inventory, `index` being the elf's position in the input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ElfStats {
+    pub index: usize,
+    pub items: usize,
+    pub total: u64,
+    pub largest: u32,
+}
+
+impl ElfStats {
+    pub fn mean(&self) -> f64 {
+        self.total as f64 / self.items.max(1) as f64
+    }
+}
+
+pub fn elf_stats(inputs: &Input) -> Vec<ElfStats> {
+    inputs
+        .iter()
+        .enumerate()
+        .map(|(index, elf)| ElfStats {
+            index,
+            items: elf.items.len(),
+            total: elf.items.iter().map(|&n| n as u64).sum(),
+            largest: elf.items.iter().copied().max().unwrap_or(0),
+        })
+        .collect()
+}
+
+/// Indices of the elves carrying more than `calories`, in input order.
+pub fn elves_above(inputs: &Input, calories: u64) -> Vec<usize> {
+    totals(inputs)
+        .enumerate()
+        .filter(|&(_, total)| total > calories)
+        .map(|(index, _)| index)
+        .collect()
+}
+
+/// The nearest-rank `p`th percentile of `sorted`, which must be sorted
+/// ascending and not be empty. A `p` over 100 counts as 100, the maximum.
+pub fn percentile(sorted: &[u64], p: u32) -> u64 {
+    let rank = (p.min(100) as usize * sorted.len()).div_ceil(100);
+    sorted[rank.max(1) - 1]
+}
+
+/// Counts `totals` in `buckets` equal ranges, one line per range, with bars
+/// scaled so that the fullest range's is `width` long.
+pub fn histogram(totals: &[u64], buckets: usize, width: usize) -> String {
+    let (Some(&min), Some(&max)) = (totals.iter().min(), totals.iter().max()) else {
+        return String::new();
+    };
+    let buckets = buckets.max(1) as u64;
+    let size = (max - min).div_ceil(buckets).max(1);
+    let mut counts = vec![0; buckets as usize];
+    for &total in totals {
+        // The last range runs up to and including `max`.
+        counts[((total - min) / size).min(buckets - 1) as usize] += 1;
+    }
+    let fullest = counts.iter().copied().max().unwrap_or(0).max(1);
+    let label_width = max.to_string().len();
+    let count_width = fullest.to_string().len();
+    let lines: Vec<String> = counts
+        .iter()
+        .enumerate()
+        .map(|(i, &count)| {
+            let from = min + size * i as u64;
+            let to = if i as u64 == buckets - 1 {
+                max
+            } else {
+                from + size - 1
+            };
+            let line = format!(
+                "{:>w$} - {:>w$} | {:>cw$} {}",
+                from,
+                to,
+                count,
+                "#".repeat((count * width).div_ceil(fullest)),
+                w = label_width,
+                cw = count_width,
+            );
+            line.trim_end().to_string()
+        })
+        .collect();
+    lines.join("\n")
+}
+
+/// A table of every elf's inventory, the elves numbered from 1, followed by
+/// the spread of the totals.
+pub fn report(inputs: &Input) -> String {
+    let stats = elf_stats(inputs);
+    let mut rows = vec![["Elf", "Name", "Items", "Total", "Mean", "Largest"].map(String::from)];
+    rows.extend(stats.iter().map(|elf| {
+        [
+            (elf.index + 1).to_string(),
+            inputs[elf.index].name.clone().unwrap_or_default(),
+            elf.items.to_string(),
+            elf.total.to_string(),
+            format!("{:.1}", elf.mean()),
+            elf.largest.to_string(),
+        ]
+    }));
+    // Names only get a column when there are any.
+    let named = inputs.iter().any(|elf| elf.name.is_some());
+    let mut widths = [0; 6];
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+    let mut lines: Vec<String> = rows
+        .iter()
+        .map(|row| {
+            let cells: Vec<String> = row
+                .iter()
+                .zip(widths)
+                .enumerate()
+                .filter(|&(i, _)| i != 1 || named)
+                .map(|(i, (cell, width))| match i {
+                    1 => format!("{:<1$}", cell, width),
+                    _ => format!("{:>1$}", cell, width),
+                })
+                .collect();
+            cells.join("  ")
+        })
+        .collect();
+
+    let mut sorted: Vec<u64> = stats.iter().map(|elf| elf.total).collect();
+    sorted.sort_unstable();
+    if !sorted.is_empty() {
+        lines.push(String::new());
+        lines.push(format!("Elves: {}", sorted.len()));
+        lines.push(format!(
+            "Totals: min {}, p10 {}, p25 {}, median {}, p75 {}, p90 {}, max {}",
+            sorted[0],
+            percentile(&sorted, 10),
+            percentile(&sorted, 25),
+            percentile(&sorted, 50),
+            percentile(&sorted, 75),
+            percentile(&sorted, 90),
+            sorted[sorted.len() - 1],
+        ));
+        lines.push(String::new());
+        lines.push(histogram(&sorted, 10, 40));
+    }
+    lines.join("\n")
+}
+
+/// `size` elves, each carrying a handful of snacks.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let snacks = rng.range(1, 15);
+            (0..snacks)
+                .map(|_| rng.range(1000, 60_000).to_string())
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    const INPUT: &'static str = include_str!("inputs");
+    // Elves are separated by blank lines, so files can simply be appended.
+    const MERGE_SEPARATOR: Option<&'static str> = Some("\n\n");
+
+    type Input = Input;
+    type Output1 = Carried;
+    type Output2 = Carried;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        parse(s)
+    }
+
+    fn answer_part1(input: &Input) -> Carried {
+        answer_part1(input)
+    }
+
+    fn answer_part2(input: &Input) -> Carried {
+        answer_part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+
+    fn report(input: &Input) -> Option<String> {
+        Some(report(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE_INPUT: &str = include_str!("../examples/example.txt");
+
+    #[test]
+    fn _part1() {
+        let inputs = parse(EXAMPLE_INPUT).unwrap();
+        assert_eq!(24000, answer_part1(&inputs).calories);
+        assert_eq!("24000", answer_part1(&inputs).to_string());
+    }
+    #[test]
+    fn _part2() {
+        let inputs = parse(EXAMPLE_INPUT).unwrap();
+        assert_eq!(45000, answer_part2(&inputs).calories);
+    }
+
+    #[test]
+    fn _names() {
+        let inputs = parse("# alice\n1000\n2000\n# bob\n5000\n\n4000\n\n#carol \n500").unwrap();
+        let names: Vec<_> = inputs.iter().map(|elf| elf.name.as_deref()).collect();
+        assert_eq!(vec![Some("alice"), Some("bob"), None, Some("carol")], names);
+        assert_eq!(vec![5000], inputs[1].items);
+        assert_eq!("5000 (bob)", answer_part1(&inputs).to_string());
+        assert_eq!(
+            "12000 (bob, elf 3, alice)",
+            answer_part2(&inputs).to_string()
+        );
+        assert_eq!(vec![1], elves_above(&inputs, 4000));
+        assert!(report(&inputs).contains("  2  bob        1   5000"));
+
+        let e = parse("# alice\n# bob\n1000").unwrap_err();
+        assert_eq!(
+            (2, 1, "expected a calorie count"),
+            (e.line, e.column, e.message.as_str())
+        );
+        let e = parse("1000\n\n# bob\n\n2000").unwrap_err();
+        assert_eq!((3, 6), (e.line, e.column));
+        let e = parse("1000\n#\n2000").unwrap_err();
+        assert_eq!("expected a name after '#'", e.message);
+        let e = parse("1000\n# bob").unwrap_err();
+        assert_eq!((2, 6), (e.line, e.column));
+    }
+
+    #[test]
+    fn _blank_lines() {
+        let expected = parse("1000\n\n2000").unwrap();
+        assert_eq!(expected, parse("\n1000\n\n\n\n2000\n\n").unwrap());
+        assert_eq!(expected, parse("1000\r\n\r\n2000\r\n").unwrap());
+        assert!(parse("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn _merged_files() {
+        // Merging joins the files with a blank line in between.
+        let team1 = "# alice\n1000\n\n2000";
+        let team2 = "3000\n# bob\n4000";
+        let merged = parse(&format!("{}\n\n{}", team1, team2)).unwrap();
+        let mut expected = parse(team1).unwrap();
+        expected.extend(parse(team2).unwrap());
+        assert_eq!(expected, merged);
+        assert_eq!(4, merged.len());
+    }
+
+    #[test]
+    fn _top_k() {
+        assert_eq!(vec![9, 7], top_k([3, 9, 1, 7], 2));
+        assert_eq!(vec![9, 3, 1], top_k([3, 9, 1], 5));
+        assert!(top_k([3, 9, 1], 0).is_empty());
+        assert!(top_k(Vec::<u64>::new(), 3).is_empty());
+    }
+
+    #[test]
+    fn _top_k_from_reader() {
+        let crlf = EXAMPLE_INPUT.replace('\n', "\r\n") + "\r\n\r\n";
+        let top = top_k_from_reader(crlf.as_bytes(), 3).unwrap();
+        assert_eq!(vec![24000, 11000, 10000], top);
+
+        let e = top_k_from_reader("1000\n\n\n2x00\n".as_bytes(), 3).unwrap_err();
+        let ReadError::Parse(e) = e else {
+            panic!("expected a parse error, got {:?}", e);
+        };
+        assert_eq!((4, 1, "2x00"), (e.line, e.column, e.text.as_str()));
+    }
+
+    #[test]
+    fn _elf_stats() {
+        let stats = elf_stats(&parse(EXAMPLE_INPUT).unwrap());
+        assert_eq!(5, stats.len());
+        let expected = ElfStats {
+            index: 3,
+            items: 3,
+            total: 24000,
+            largest: 9000,
+        };
+        assert_eq!(expected, stats[3]);
+        assert_eq!(8000.0, stats[3].mean());
+    }
+
+    #[test]
+    fn _elves_above() {
+        let inputs = parse(EXAMPLE_INPUT).unwrap();
+        assert_eq!(vec![2, 3], elves_above(&inputs, 10000));
+        assert_eq!(vec![0, 1, 2, 3, 4], elves_above(&inputs, 0));
+        assert!(elves_above(&inputs, 24000).is_empty());
+    }
+
+    #[test]
+    fn _percentile_and_histogram() {
+        let sorted = [4000, 6000, 10000, 11000, 24000];
+        let percentiles = [0, 25, 50, 90, 100, 250].map(|p| percentile(&sorted, p));
+        assert_eq!([4000, 6000, 10000, 24000, 24000, 24000], percentiles);
+
+        let expected = "4000 - 5999 | 2 ####\n6000 - 7999 | 0\n8000 - 9999 | 1 ##";
+        assert_eq!(expected, histogram(&[4000, 4500, 9999], 3, 4));
+        assert_eq!("5 - 5 | 2 ####", histogram(&[5, 5], 1, 4));
+        assert_eq!("", histogram(&[], 3, 4));
+    }
+
+    #[test]
+    fn _report() {
+        let report = report(&parse(EXAMPLE_INPUT).unwrap());
+        let lines: Vec<&str> = report.lines().collect();
+        assert_eq!("Elf  Items  Total     Mean  Largest", lines[0]);
+        assert_eq!("  4      3  24000   8000.0     9000", lines[4]);
+        assert!(report.contains("median 10000, p75 11000"));
+        assert_eq!(10, report.lines().filter(|l| l.contains(" | ")).count());
+    }
+
+    #[test]
+    fn _parse_error() {
+        let e = parse("1000\n2x00\n\n3000").unwrap_err();
+        assert_eq!((2, 1), (e.line, e.column));
+    }
+
+    fn to_text(input: &Input) -> String {
+        let elves: Vec<String> = input
+            .iter()
+            .map(|elf| {
+                let name = elf.name.iter().map(|name| format!("# {}", name));
+                let items = elf.items.iter().map(|n| n.to_string());
+                name.chain(items).collect::<Vec<_>>().join("\n")
+            })
+            .collect();
+        elves.join("\n\n")
+    }
+
+    fn input_strategy() -> impl Strategy<Value = Input> {
+        let elf = (
+            prop::option::of("[a-z]{1,8}"),
+            prop::collection::vec(any::<u32>(), 1..5),
+        );
+        prop::collection::vec(elf.prop_map(|(name, items)| Elf { name, items }), 1..10)
+    }
+
+    /// A line of a calorie list, valid or not, with its line ending.
+    fn line_strategy() -> impl Strategy<Value = String> {
+        let line = prop_oneof![
+            Just(String::new()),
+            Just("#".to_string()),
+            Just("x1".to_string()),
+            "# [a-z]{1,3}",
+            "[0-9]{1,4}",
+        ];
+        let ending = prop_oneof![Just(""), Just("\n"), Just("\r\n")];
+        (line, ending).prop_map(|(line, ending)| line + ending)
+    }
+
+    proptest! {
+        #[test]
+        fn _round_trip(input in input_strategy()) {
+            prop_assert_eq!(&input, &parse(&to_text(&input)).unwrap());
+        }
+
+        #[test]
+        fn _streaming_grammar(lines in prop::collection::vec(line_strategy(), 0..12)) {
+            let s = lines.join("");
+            let error = |e: &ParseError| (e.line, e.column, e.message.clone());
+            let parsed = parse(&s).map(|input| top_k(totals(&input), 3));
+            let streamed = top_k_from_reader(s.as_bytes(), 3).map_err(|e| match e {
+                ReadError::Parse(e) => e,
+                ReadError::Io(e) => panic!("reading from a slice failed: {}", e),
+            });
+            prop_assert_eq!(parsed.map_err(|e| error(&e)), streamed.map_err(|e| error(&e)));
+        }
+
+        #[test]
+        fn _streaming(input in input_strategy()) {
+            let streamed = top_k_from_reader(to_text(&input).as_bytes(), 3).unwrap();
+            prop_assert_eq!(top_k(totals(&input), 3), streamed);
+        }
+    }
+}
@@ -1,88 +1,64 @@
-#![feature(test)]
-
-extern crate test;
-
-type Input = Vec<Vec<u32>>;
-
-fn parse(s: &str) -> Input {
-    s.split("\n\n")
-        .map(|t| {
-            t.split("\n")
-                .map(|n| n.parse::<u32>().unwrap())
-                .collect::<Vec<_>>()
-        })
-        .collect::<Vec<_>>()
-}
-
-fn answer_part1(inputs: &Input) -> usize {
-    let mut sums: Vec<u32> = inputs.iter().map(|x| x.iter().sum()).collect();
-    sums.sort();
-    *sums.last().unwrap() as usize
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, IsTerminal};
+use std::process::ExitCode;
+
+use aoc_common::{print_answer, Part, Solution};
+use day1::ReadError;
+
+const STREAM_USAGE: &str = "\
+Usage: day1 --stream [INPUT]
+
+Reads the calorie list from the file INPUT, or from stdin if INPUT is '-' or
+input is piped in, line by line in constant memory, so lists of any size can
+be answered. Without either, the input bundled with the binary is used. Only
+the calories are given, without the elves' names.";
+
+/// Answers from a calorie list read line by line, for `--stream`.
+fn stream(path: Option<&str>) -> Result<Vec<u64>, String> {
+    let top = match path {
+        Some("-") => day1::top_k_from_reader(io::stdin().lock(), 3),
+        // Falls back to the bundled input like `aoc_common::run` does.
+        None => {
+            let bundled = day1::Day1::INPUT.as_bytes();
+            let mut stdin = io::stdin().lock();
+            if stdin.is_terminal() {
+                day1::top_k_from_reader(bundled, 3)
+            } else {
+                match stdin.fill_buf() {
+                    Ok([]) => day1::top_k_from_reader(bundled, 3),
+                    Ok(_) => day1::top_k_from_reader(stdin, 3),
+                    Err(e) => Err(e.into()),
+                }
+            }
+        }
+        Some(path) => File::open(path)
+            .map_err(ReadError::from)
+            .and_then(|file| day1::top_k_from_reader(BufReader::new(file), 3)),
+    };
+    top.map_err(|e| match e {
+        ReadError::Io(e) => format!("error: failed to read input: {}", e),
+        ReadError::Parse(e) => e.report(),
+    })
 }
 
-fn answer_part2(inputs: &Input) -> usize {
-    let mut sums: Vec<u32> = inputs.iter().map(|x| x.iter().sum()).collect();
-    sums.sort();
-    sums[(sums.len() - 3)..].iter().sum::<u32>() as usize
-}
-
-fn main() {
-    let inputs = parse(include_str!("inputs"));
-    println!("Part 1 = {}", answer_part1(&inputs));
-    println!("Part 2 = {}", answer_part2(&inputs));
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const EXAMPLE_INPUT: &'static str = "1000
-2000
-3000
-
-4000
-
-5000
-6000
-
-7000
-8000
-9000
-
-10000";
-
-    #[test]
-    fn _part1() {
-        let inputs = parse(EXAMPLE_INPUT);
-        assert_eq!(24000, answer_part1(&inputs));
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().map(String::as_str) != Some("--stream") {
+        return aoc_common::run(day1::Day1);
     }
-    #[test]
-    fn _part2() {
-        let inputs = parse(EXAMPLE_INPUT);
-        assert_eq!(45000, answer_part2(&inputs));
+    if args.len() > 2 {
+        eprintln!("{}", STREAM_USAGE);
+        return ExitCode::FAILURE;
     }
-
-    #[bench]
-    fn bench_parse(b: &mut test::Bencher) {
-        let input = include_str!("inputs");
-        b.iter(|| {
-            test::black_box(parse(input));
-        });
-    }
-
-    #[bench]
-    fn bench_answer_part1(b: &mut test::Bencher) {
-        let inputs = parse(include_str!("inputs"));
-        b.iter(|| {
-            test::black_box(answer_part1(&inputs));
-        });
-    }
-
-    #[bench]
-    fn bench_answer_part2(b: &mut test::Bencher) {
-        let inputs = parse(include_str!("inputs"));
-        b.iter(|| {
-            test::black_box(answer_part2(&inputs));
-        });
+    match stream(args.get(1).map(String::as_str)) {
+        Ok(top) => {
+            print_answer(Part::One, &top.first().unwrap_or(&0).to_string());
+            print_answer(Part::Two, &top.iter().sum::<u64>().to_string());
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
     }
 }
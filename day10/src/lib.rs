@@ -0,0 +1,271 @@
+use std::fmt;
+
+use aoc_common::image::{upscale, Rgb};
+use aoc_common::{Grid, ParseError, Rng, Simulation, Solution};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Instr {
+    // two cycles
+    Addx(i32),
+    // one cycle
+    NoOp,
+}
+
+pub type Input = Vec<Instr>;
+
+pub fn parse(s: &str) -> Result<Input, ParseError> {
+    let input = s;
+    s.lines()
+        .map(|s| {
+            if s == "noop" {
+                Ok(Instr::NoOp)
+            } else if let Some(("addx", n)) = s.split_once(" ") {
+                let n = n
+                    .parse::<i32>()
+                    .map_err(|_| ParseError::at(input, n, "expected a number"))?;
+                Ok(Instr::Addx(n))
+            } else {
+                Err(ParseError::at(input, s, "expected 'noop' or 'addx <n>'"))
+            }
+        })
+        .collect()
+}
+
+/// The CPU running a program, one clock cycle per step.
+pub struct Cpu<'a> {
+    program: &'a [Instr],
+    pc: usize,
+    /// Whether the first of an `addx`'s two cycles has passed.
+    mid_addx: bool,
+    cycle: i32,
+    x: i32,
+}
+
+/// The cycle in progress and the value of `X` during it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CpuState {
+    pub cycle: i32,
+    pub x: i32,
+}
+
+impl<'a> Cpu<'a> {
+    pub fn new(program: &'a Input) -> Self {
+        Cpu {
+            program,
+            pc: 0,
+            mid_addx: false,
+            cycle: 1,
+            x: 1,
+        }
+    }
+}
+
+impl Simulation for Cpu<'_> {
+    type State = CpuState;
+
+    fn step(&mut self) {
+        match self.program.get(self.pc) {
+            None => return,
+            Some(Instr::NoOp) => self.pc += 1,
+            Some(Instr::Addx(_)) if !self.mid_addx => self.mid_addx = true,
+            Some(Instr::Addx(n)) => {
+                self.x += n;
+                self.pc += 1;
+                self.mid_addx = false;
+            }
+        }
+        self.cycle += 1;
+    }
+
+    fn is_done(&self) -> bool {
+        self.pc == self.program.len()
+    }
+
+    fn snapshot(&self) -> CpuState {
+        CpuState {
+            cycle: self.cycle,
+            x: self.x,
+        }
+    }
+}
+
+pub fn answer_part1(inputs: &Input) -> i32 {
+    let mut cpu = Cpu::new(inputs);
+    let mut out: i32 = 0;
+    while !cpu.is_done() {
+        let CpuState { cycle, x } = cpu.snapshot();
+        if cycle % 40 == 20 {
+            out += x * cycle;
+        }
+        cpu.step();
+    }
+    out
+}
+
+fn char_for_cycle(pos: i32, cycle: i32) -> char {
+    let pos_1 = (cycle - 2) % 40;
+    let pos_2 = (cycle - 1) % 40;
+    let pos_3 = cycle % 40;
+    if pos == pos_1 || pos == pos_2 || pos == pos_3 {
+        '#'
+    } else {
+        '.'
+    }
+}
+
+pub fn answer_part2(inputs: &Input) -> String {
+    let mut cpu = Cpu::new(inputs);
+    let mut buf = String::with_capacity(inputs.len() * 2);
+    while !cpu.is_done() {
+        let CpuState { cycle, x } = cpu.snapshot();
+        buf.push(char_for_cycle(x, cycle));
+        cpu.step();
+    }
+    buf
+}
+
+/// The CRT image, one character per pixel, displayed as rows of 40.
+pub struct Crt(String);
+
+impl fmt::Display for Crt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, row) in self.0.as_bytes().chunks(40).enumerate() {
+            if idx > 0 {
+                writeln!(f)?;
+            }
+            f.write_str(std::str::from_utf8(row).unwrap())?;
+        }
+        Ok(())
+    }
+}
+
+/// `size` instructions. `X` is kept on the screen, which also keeps part 1's
+/// signal strength from overflowing for a while longer.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut x = 1;
+    (0..size)
+        .map(|_| {
+            if rng.chance(0.3) {
+                return "noop".to_string();
+            }
+            let n = rng.range(-x.min(10), (40 - x).min(10));
+            x += n;
+            format!("addx {}", n)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The CRT screen, each pixel drawn as an 8 by 8 block.
+pub fn render(input: &Input) -> Grid<Rgb> {
+    let pixels = answer_part2(input);
+    let height = pixels.len().div_ceil(40);
+    let mut cells: Vec<_> = pixels.bytes().map(|c| c == b'#').collect();
+    cells.resize(40 * height, false);
+    let screen = Grid::from_vec(40, height, cells);
+    let image = screen.map(|&lit| if lit { [80, 255, 80] } else { [10, 25, 10] });
+    upscale(&image, 8)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const INPUT: &'static str = include_str!("inputs");
+
+    type Input = Input;
+    type Output1 = i32;
+    type Output2 = Crt;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        parse(s)
+    }
+
+    fn answer_part1(input: &Input) -> i32 {
+        answer_part1(input)
+    }
+
+    fn answer_part2(input: &Input) -> Crt {
+        Crt(answer_part2(input))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+
+    fn render(input: &Input) -> Option<Grid<Rgb>> {
+        Some(render(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE_INPUT: &str = include_str!("../examples/example.txt");
+
+    #[test]
+    fn _part1() {
+        let inputs = parse(EXAMPLE_INPUT).unwrap();
+        assert_eq!(13140, answer_part1(&inputs));
+    }
+    #[test]
+    fn _part2() {
+        use assert_str::*;
+        let eoutput_example = "##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....";
+        let expected_output = eoutput_example
+            .lines()
+            .flat_map(|x| x.chars())
+            .collect::<String>();
+        let output = answer_part2(&parse(EXAMPLE_INPUT).unwrap());
+        assert_str_trim_eq!(expected_output, output);
+    }
+
+    #[test]
+    fn _cpu_steps() {
+        let inputs = parse("noop\naddx 3\naddx -5").unwrap();
+        let xs: Vec<_> = Cpu::new(&inputs).record().iter().map(|s| s.x).collect();
+        assert_eq!(vec![1, 1, 1, 4, 4, -1], xs);
+    }
+
+    #[test]
+    fn _render() {
+        let image = render(&parse(EXAMPLE_INPUT).unwrap());
+        assert_eq!((320, 48), (image.width(), image.height()));
+        assert_eq!([80, 255, 80], image[(7, 7)]);
+        assert_eq!([10, 25, 10], image[(16, 0)]);
+    }
+
+    #[test]
+    fn _parse_error() {
+        let e = parse("noop\naddx").unwrap_err();
+        assert_eq!((2, 1), (e.line, e.column));
+    }
+
+    fn to_text(input: &Input) -> String {
+        let instrs: Vec<String> = input
+            .iter()
+            .map(|instr| match instr {
+                Instr::Addx(n) => format!("addx {}", n),
+                Instr::NoOp => "noop".to_string(),
+            })
+            .collect();
+        instrs.join("\n")
+    }
+
+    proptest! {
+        #[test]
+        fn _round_trip(input in prop::collection::vec(
+            prop_oneof![Just(Instr::NoOp), any::<i32>().prop_map(Instr::Addx)],
+            1..10,
+        )) {
+            prop_assert_eq!(&input, &parse(&to_text(&input)).unwrap());
+        }
+    }
+}
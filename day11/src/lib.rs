@@ -0,0 +1,780 @@
+extern crate nom;
+
+use aoc_common::{ParseError, Rng, Simulation, Solution};
+use nom::{
+    branch as B,
+    bytes::complete::tag,
+    character::complete::{digit1, multispace0, newline, space1},
+    combinator as C,
+    error::{context, VerboseError},
+    multi::{self as M},
+    sequence::{self as S},
+    Finish,
+};
+pub type N = u128;
+
+type IResult<'a, T> = nom::IResult<&'a str, T, VerboseError<&'a str>>;
+
+/// A token of a monkey's operation, such as `old * 19`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Op {
+    N(N),
+    Old,
+    Mul,
+    Add,
+}
+
+/// Evaluates `ops` on `old`, modulo `modulus` if there is one, or returns
+/// `None` if the result overflows.
+fn eval_op(ops: &[Op], old: N, modulus: Option<N>) -> Option<N> {
+    let reduce = |n: N| modulus.map_or(n, |m| n % m);
+    let operand = |op: Op| match op {
+        Op::N(n) => reduce(n),
+        Op::Old => reduce(old),
+        _ => panic!("unexpected op"),
+    };
+    let mut n = operand(ops[0]);
+    let mut i = 1;
+    while i < ops.len() {
+        let n2 = operand(ops[i + 1]);
+        n = match ops[i] {
+            Op::Mul => n.checked_mul(n2)?,
+            Op::Add => n.checked_add(n2)?,
+            _ => panic!("unexpected number"),
+        };
+        n = reduce(n);
+        i += 2;
+    }
+    Some(n)
+}
+
+fn gcd(a: N, b: N) -> N {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// The least common multiple of `divisors`, if it fits in a `u64`. Worry
+/// levels below it can be multiplied together without overflowing.
+fn common_divisor(divisors: impl IntoIterator<Item = N>) -> Option<N> {
+    divisors.into_iter().try_fold(1, |m: N, d| {
+        let lcm = (m / gcd(m, d)).checked_mul(d)?;
+        (lcm <= u64::MAX as N).then_some(lcm)
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Monkey {
+    pub id: u8,
+    pub starting_items: Vec<N>,
+    pub ops: Vec<Op>,
+    /// The monkey throws to `if_true` when an item's worry level is
+    /// divisible by this, and to `if_false` otherwise.
+    pub test_divisible: N,
+    pub if_true: u8,
+    pub if_false: u8,
+    pub inspection_count: u64,
+}
+
+impl Monkey {
+    /// Returns false, with only some items thrown, if a worry level
+    /// overflows.
+    fn run_1(&mut self, mails: &mut Vec<(u8, N)>) -> bool {
+        self.inspection_count += self.starting_items.len() as u64;
+        for old in self.starting_items.drain(0..) {
+            let Some(new) = eval_op(&self.ops, old, None) else {
+                return false;
+            };
+            let new = new / 3;
+            let next_id = if new.is_multiple_of(self.test_divisible) {
+                self.if_true
+            } else {
+                self.if_false
+            };
+            mails.push((next_id, new))
+        }
+        true
+    }
+    fn run_2(&mut self, mails: &mut Vec<(u8, N)>, common_divisor: N) {
+        self.inspection_count += self.starting_items.len() as u64;
+        for old in self.starting_items.drain(0..) {
+            let new = eval_op(&self.ops, old, Some(common_divisor))
+                .expect("worry levels below a u64 can not overflow");
+            let next_id = if new.is_multiple_of(self.test_divisible) {
+                self.if_true
+            } else {
+                self.if_false
+            };
+            mails.push((next_id, new))
+        }
+    }
+}
+
+pub type Input = Vec<Monkey>;
+
+fn parse_u8(s: &str) -> IResult<'_, u8> {
+    C::map_res(digit1, |n: &str| n.parse::<u8>())(s)
+}
+fn parse_n(s: &str) -> IResult<'_, N> {
+    C::map_res(digit1, |n: &str| n.parse::<N>())(s)
+}
+
+fn parse_op(s: &str) -> IResult<'_, Op> {
+    B::alt((
+        C::map(tag("old"), |_| Op::Old),
+        C::map(parse_n, Op::N),
+        C::map(tag("*"), |_| Op::Mul),
+        C::map(tag("+"), |_| Op::Add),
+    ))(s)
+}
+
+fn parse_monkey(s: &str) -> IResult<'_, Monkey> {
+    let (s, id) = S::delimited(tag("Monkey "), parse_u8, S::pair(tag(":"), newline))(s)?;
+    // Past the header a malformed line is an error, not the end of the list.
+    C::cut(move |s| parse_monkey_body(s, id))(s)
+}
+
+fn parse_monkey_body(s: &str, id: u8) -> IResult<'_, Monkey> {
+    let (s, starting_items) = context(
+        "Starting items line",
+        S::delimited(
+            tag("  Starting items: "),
+            M::separated_list1(tag(", "), parse_n),
+            newline,
+        ),
+    )(s)?;
+
+    let (s, ops) = context(
+        "Operation line",
+        S::delimited(
+            tag("  Operation: new = "),
+            M::separated_list1(space1, parse_op),
+            newline,
+        ),
+    )(s)?;
+
+    let (s, test_divisible) = context(
+        "Test line",
+        S::delimited(tag("  Test: divisible by "), parse_n, newline),
+    )(s)?;
+
+    let (s, if_true) = context(
+        "If true line",
+        S::delimited(tag("    If true: throw to monkey "), parse_u8, newline),
+    )(s)?;
+
+    let (s, if_false) = context(
+        "If false line",
+        S::preceded(tag("    If false: throw to monkey "), parse_u8),
+    )(s)?;
+
+    Ok((
+        s,
+        Monkey {
+            id,
+            starting_items,
+            ops,
+            test_divisible,
+            if_true,
+            if_false,
+            inspection_count: 0,
+        },
+    ))
+}
+
+pub fn parse(s: &str) -> Result<Input, ParseError> {
+    let (_, mut monkeys) = C::all_consuming(S::terminated(
+        M::separated_list1(S::pair(newline, newline), parse_monkey),
+        multispace0,
+    ))(s)
+    .finish()
+    .map_err(|e| {
+        // nom's contexts are static, so the monkey is added here, from the
+        // header above where parsing failed.
+        let at = e
+            .errors
+            .first()
+            .map_or(s.len(), |(at, _)| s.len() - at.len());
+        let monkey = s[..at]
+            .rfind("Monkey ")
+            .and_then(|i| s[i + "Monkey ".len()..].split(':').next());
+        match monkey {
+            Some(id) if e.errors.len() > 1 => {
+                ParseError::from_nom(s, e).in_context(format!("Monkey {}", id))
+            }
+            _ => ParseError::from_nom(s, e),
+        }
+    })?;
+    monkeys.sort_by_key(|a| a.id);
+    if let Some(e) = unsolvable(s, &monkeys).into_iter().next() {
+        return Err(e);
+    }
+    Ok(monkeys)
+}
+
+/// The header of the `i`th monkey in `s`, and the text from there on.
+fn monkey_text<'a>(s: &'a str, input: &Input, i: usize) -> Option<(&'a str, &'a str)> {
+    let monkey = &input[i];
+    let header = format!("Monkey {}:", monkey.id);
+    // Sorting keeps monkeys with the same id in the order they appear.
+    let nth = input[..i].iter().filter(|m| m.id == monkey.id).count();
+    let (start, header) = s.match_indices(&header).nth(nth)?;
+    Some((header, &s[start..]))
+}
+
+/// The value following `label` on one of the lines of a monkey's `block`, or
+/// its `header` if there is no such line.
+fn line_value<'a>(header: &'a str, block: &'a str, label: &str) -> &'a str {
+    block
+        .lines()
+        .take(6)
+        .find_map(|line| line.trim_start().strip_prefix(label))
+        .unwrap_or(header)
+}
+
+/// Problems that would make the solvers panic, which `parse` rejects: fewer
+/// than two monkeys, operations that can not be evaluated, tests for
+/// divisibility by zero, throws to monkeys that do not exist, divisors with
+/// too large a common multiple for part 2 and worry levels that overflow in
+/// part 1.
+fn unsolvable(s: &str, input: &Input) -> Vec<ParseError> {
+    let mut problems = Vec::new();
+    if input.len() < 2 {
+        problems.push(ParseError::at_end(s, "expected at least two monkeys"));
+    }
+    let mut divisors_fit = true;
+    for (i, monkey) in input.iter().enumerate() {
+        // Point at the end of the input if the monkey can not be found.
+        let end = &s[s.len()..];
+        let (header, block) = monkey_text(s, input, i).unwrap_or((end, end));
+        let value = |label: &str| line_value(header, block, label);
+        let in_monkey = |e: ParseError| e.in_context(format!("Monkey {}", monkey.id));
+
+        let ops = &monkey.ops;
+        let operand = |op: &Op| matches!(op, Op::N(_) | Op::Old);
+        let well_formed = ops.len() % 2 == 1
+            && ops
+                .iter()
+                .enumerate()
+                .all(|(i, op)| operand(op) == (i % 2 == 0));
+        if !well_formed {
+            problems.push(in_monkey(ParseError::at(
+                s,
+                value("Operation: new = "),
+                "expected operands separated by '+' or '*'",
+            )));
+        }
+        if monkey.test_divisible == 0 {
+            problems.push(in_monkey(ParseError::at(
+                s,
+                value("Test: divisible by "),
+                "can not test divisibility by zero",
+            )));
+        } else if divisors_fit
+            && common_divisor(input[..=i].iter().map(|m| m.test_divisible)).is_none()
+        {
+            // Only reported at the first monkey to push it over.
+            divisors_fit = false;
+            problems.push(in_monkey(ParseError::at(
+                s,
+                value("Test: divisible by "),
+                "the divisors' least common multiple does not fit in 64 bits",
+            )));
+        }
+        for (label, target) in [
+            ("If true: throw to monkey ", monkey.if_true),
+            ("If false: throw to monkey ", monkey.if_false),
+        ] {
+            if target as usize >= input.len() {
+                problems.push(in_monkey(ParseError::at(
+                    s,
+                    value(label),
+                    format!("no monkey {}", target),
+                )));
+            }
+        }
+    }
+    if problems.is_empty() {
+        let mut game = KeepAway::new(input, Worry::Relieved, 20);
+        game.run();
+        if let Some(i) = game.overflow() {
+            let end = &s[s.len()..];
+            let (header, block) = monkey_text(s, input, i).unwrap_or((end, end));
+            let e = ParseError::at(
+                s,
+                line_value(header, block, "Operation: new = "),
+                "worry levels overflow within 20 rounds",
+            );
+            problems.push(e.in_context(format!("Monkey {}", input[i].id)));
+        }
+    }
+    problems
+}
+
+/// Checks that the monkeys are numbered from 0 without gaps, as monkeys
+/// throw to each other by position. Inputs that `parse` would reject are
+/// reported too.
+pub fn check(s: &str, input: &Input) -> Vec<ParseError> {
+    let mut problems = Vec::new();
+    for (i, monkey) in input.iter().enumerate() {
+        let Some((header, _)) = monkey_text(s, input, i) else {
+            continue;
+        };
+        if monkey.id as usize != i {
+            let defined_before = input[..i].iter().any(|m| m.id == monkey.id);
+            let message = if defined_before {
+                format!("monkey {} is defined twice", monkey.id)
+            } else {
+                format!("expected monkey {}", i)
+            };
+            problems.push(ParseError::at(s, header, message));
+        }
+    }
+    problems.extend(unsolvable(s, input));
+    problems
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Worry {
+    /// Worry levels are divided by three after each inspection.
+    Relieved,
+    /// Worry levels are only kept in check modulo the monkeys' divisors.
+    Unrelieved,
+}
+
+/// The monkeys playing keep away, one round per step.
+pub struct KeepAway {
+    monkeys: Vec<Monkey>,
+    worry: Worry,
+    common_divisor: N,
+    round: usize,
+    rounds: usize,
+    mails: Vec<(u8, N)>,
+    /// The position of the monkey whose operation overflowed, ending the game.
+    overflow: Option<usize>,
+}
+
+impl KeepAway {
+    /// Panics for unrelieved worry if the monkeys' divisors have a least
+    /// common multiple beyond a `u64`, which `parse` rejects.
+    pub fn new(monkeys: &Input, worry: Worry, rounds: usize) -> Self {
+        let common_divisor = match worry {
+            Worry::Relieved => 1,
+            Worry::Unrelieved => common_divisor(monkeys.iter().map(|x| x.test_divisible))
+                .expect("divisors' least common multiple does not fit in 64 bits"),
+        };
+        KeepAway {
+            monkeys: monkeys.clone(),
+            worry,
+            common_divisor,
+            round: 0,
+            rounds,
+            mails: Vec::new(),
+            overflow: None,
+        }
+    }
+
+    /// The position of the monkey whose operation overflowed a worry level,
+    /// if one did. Only relieved worry levels can overflow, as unrelieved
+    /// ones are kept below the common divisor.
+    pub fn overflow(&self) -> Option<usize> {
+        self.overflow
+    }
+
+    /// The inspection counts of the two most active monkeys multiplied, or 0
+    /// with fewer than two monkeys.
+    pub fn monkey_business(&self) -> u64 {
+        let mut counts: Vec<_> = self.monkeys.iter().map(|m| m.inspection_count).collect();
+        counts.sort_unstable_by_key(|&n| std::cmp::Reverse(n));
+        counts.iter().chain(&[0, 0]).take(2).product()
+    }
+}
+
+impl Simulation for KeepAway {
+    type State = Vec<Monkey>;
+
+    fn step(&mut self) {
+        if self.is_done() {
+            return;
+        }
+        for i in 0..self.monkeys.len() {
+            let thrown = match self.worry {
+                Worry::Relieved => self.monkeys[i].run_1(&mut self.mails),
+                Worry::Unrelieved => {
+                    self.monkeys[i].run_2(&mut self.mails, self.common_divisor);
+                    true
+                }
+            };
+            for (id, n) in self.mails.drain(0..) {
+                self.monkeys[id as usize].starting_items.push(n);
+            }
+            if !thrown {
+                self.overflow = Some(i);
+                return;
+            }
+        }
+        self.round += 1;
+    }
+
+    fn is_done(&self) -> bool {
+        self.round == self.rounds || self.overflow.is_some()
+    }
+
+    fn snapshot(&self) -> Vec<Monkey> {
+        self.monkeys.clone()
+    }
+}
+
+pub fn answer_part1(inputs: &Input) -> u64 {
+    let mut game = KeepAway::new(inputs, Worry::Relieved, 20);
+    game.run();
+    game.monkey_business()
+}
+
+pub fn answer_part2(inputs: &Input) -> u64 {
+    let mut game = KeepAway::new(inputs, Worry::Unrelieved, 10_000);
+    game.run();
+    game.monkey_business()
+}
+
+/// Eight monkeys sharing `size` items. Monkeys that multiply only throw to
+/// monkeys before them, so an item is multiplied at most once per round and
+/// part 1's worry levels stay far from overflowing.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    const MONKEYS: usize = 8;
+    const PRIMES: [u32; MONKEYS] = [2, 3, 5, 7, 11, 13, 17, 19];
+    let mut items = vec![Vec::new(); MONKEYS];
+    for i in 0..size.max(MONKEYS) {
+        // Every monkey needs at least one item to be parsed.
+        let monkey = if i < MONKEYS { i } else { rng.index(MONKEYS) };
+        items[monkey].push(rng.range(50, 100).to_string());
+    }
+    let mut primes = PRIMES;
+    rng.shuffle(&mut primes);
+
+    (0..MONKEYS)
+        .map(|id| {
+            let multiplies = id == MONKEYS - 1 || (id > 0 && rng.chance(0.5));
+            let (op, targets) = if multiplies {
+                (format!("old * {}", rng.range(2, 20)), 0..id)
+            } else {
+                (format!("old + {}", rng.range(1, 9)), id + 1..MONKEYS)
+            };
+            let mut target = || targets.start + rng.index(targets.len());
+            format!(
+                "Monkey {}:\n  Starting items: {}\n  Operation: new = {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}",
+                id,
+                items[id].join(", "),
+                op,
+                primes[id],
+                target(),
+                target(),
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const INPUT: &'static str = include_str!("inputs");
+
+    type Input = Input;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        parse(s)
+    }
+
+    fn answer_part1(input: &Input) -> u64 {
+        answer_part1(input)
+    }
+
+    fn answer_part2(input: &Input) -> u64 {
+        answer_part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+
+    fn check(s: &str, input: &Input) -> Vec<ParseError> {
+        check(s, input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE_INPUT: &str = include_str!("../examples/example.txt");
+
+    #[test]
+    fn _part1() {
+        let inputs = parse(EXAMPLE_INPUT).unwrap();
+        assert_eq!(10_605, answer_part1(&inputs));
+    }
+
+    #[test]
+    fn _part2() {
+        let inputs = parse(EXAMPLE_INPUT).unwrap();
+        let expect: u64 = 2_713_310_158;
+        assert_eq!(expect, answer_part2(&inputs));
+    }
+
+    #[test]
+    fn _keep_away_steps() {
+        let inputs = parse(EXAMPLE_INPUT).unwrap();
+        let mut game = KeepAway::new(&inputs, Worry::Relieved, 20);
+        game.step();
+        let items: Vec<_> = game
+            .snapshot()
+            .into_iter()
+            .map(|m| m.starting_items)
+            .collect();
+        assert_eq!(vec![20, 23, 27, 26], items[0]);
+        assert_eq!(vec![2080, 25, 167, 207, 401, 1046], items[1]);
+        assert_eq!(19, game.run());
+    }
+
+    #[test]
+    fn _parse_error() {
+        let e = parse(&EXAMPLE_INPUT.replace("old * 19", "old ? 19")).unwrap_err();
+        assert_eq!((3, 23), (e.line, e.column));
+        assert_eq!(vec!["Operation line", "Monkey 0"], e.context);
+        let e = parse(&EXAMPLE_INPUT.replace("by 17", "by x")).unwrap_err();
+        assert_eq!("expected a number", e.message);
+        assert_eq!(vec!["Test line", "Monkey 3"], e.context);
+    }
+
+    #[test]
+    fn _unsolvable() {
+        let error = |s: &str| {
+            let e = parse(s).unwrap_err();
+            (e.line, e.message, e.context)
+        };
+        let s = EXAMPLE_INPUT.replace("old * old", "old * old +");
+        assert_eq!(
+            (
+                17,
+                "expected operands separated by '+' or '*'".to_string(),
+                vec!["Monkey 2".to_string()]
+            ),
+            error(&s)
+        );
+        let s = EXAMPLE_INPUT.replace("by 17", "by 0");
+        assert_eq!(25, error(&s).0);
+        let s = EXAMPLE_INPUT.replace("throw to monkey 3", "throw to monkey 4");
+        assert_eq!((6, "no monkey 4".to_string()), (error(&s).0, error(&s).1));
+        let one = EXAMPLE_INPUT.split("\n\n").next().unwrap();
+        assert_eq!("expected at least two monkeys", error(one).1);
+    }
+
+    #[test]
+    fn _overflow() {
+        let two = |op: &str, d1: N, d2: N| {
+            format!(
+                "Monkey 0:\n  Starting items: 79\n  Operation: new = {}\n  Test: divisible by {}\n    If true: throw to monkey 1\n    If false: throw to monkey 1\n\n\
+                 Monkey 1:\n  Starting items: 54\n  Operation: new = {}\n  Test: divisible by {}\n    If true: throw to monkey 0\n    If false: throw to monkey 0",
+                op, d1, op, d2
+            )
+        };
+        let error = |s: &str| {
+            let e = parse(s).unwrap_err();
+            (e.line, e.message, e.context)
+        };
+        let too_large = "the divisors' least common multiple does not fit in 64 bits".to_string();
+        assert_eq!(
+            (4, too_large.clone(), vec!["Monkey 0".to_string()]),
+            error(&two("old + 1", 18446744073709551629, 18446744073709551631))
+        );
+        assert_eq!(
+            (11, too_large, vec!["Monkey 1".to_string()]),
+            error(&two("old + 1", 1 << 40, (1 << 40) - 1))
+        );
+        let e = parse(&two("old * old", 23, 19)).unwrap_err();
+        assert_eq!(
+            (3, "worry levels overflow within 20 rounds"),
+            (e.line, e.message.as_str())
+        );
+
+        // Divisors just within 64 bits leave part 2 room to square.
+        let input = parse(&two("old + 1", 1 << 32, (1 << 32) - 1)).unwrap();
+        assert_eq!(39 * 40, answer_part1(&input));
+        let mut squares = input.clone();
+        squares[0].ops = vec![Op::Old, Op::Mul, Op::Old];
+        let mut game = KeepAway::new(&squares, Worry::Unrelieved, 100);
+        game.run();
+        assert_eq!((None, 199 * 200), (game.overflow(), game.monkey_business()));
+    }
+
+    #[test]
+    fn _check() {
+        assert!(check(EXAMPLE_INPUT, &parse(EXAMPLE_INPUT).unwrap()).is_empty());
+
+        let s = EXAMPLE_INPUT.replace("Monkey 2:", "Monkey 1:");
+        let problems = check(&s, &parse(&s).unwrap());
+        let found: Vec<_> = problems
+            .iter()
+            .map(|e| (e.line, e.message.as_str()))
+            .collect();
+        assert_eq!(vec![(15, "monkey 1 is defined twice")], found);
+
+        let s = EXAMPLE_INPUT.replace("Monkey 3:", "Monkey 5:");
+        let problems = check(&s, &parse(&s).unwrap());
+        assert_eq!("expected monkey 3", problems[0].message);
+        let problems = check(&s, &parse(&s).unwrap()[..1].to_vec());
+        assert_eq!(3, problems.len());
+        assert_eq!("expected at least two monkeys", problems[0].message);
+    }
+
+    fn to_text(input: &Input) -> String {
+        let monkeys: Vec<String> = input
+            .iter()
+            .map(|m| {
+                let items: Vec<String> = m.starting_items.iter().map(|n| n.to_string()).collect();
+                let ops: Vec<String> = m
+                    .ops
+                    .iter()
+                    .map(|op| match op {
+                        Op::N(n) => n.to_string(),
+                        Op::Old => "old".to_string(),
+                        Op::Mul => "*".to_string(),
+                        Op::Add => "+".to_string(),
+                    })
+                    .collect();
+                format!(
+                    "Monkey {}:\n  Starting items: {}\n  Operation: new = {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}",
+                    m.id,
+                    items.join(", "),
+                    ops.join(" "),
+                    m.test_divisible,
+                    m.if_true,
+                    m.if_false
+                )
+            })
+            .collect();
+        monkeys.join("\n\n")
+    }
+
+    /// Mostly small numbers, which stay small even when squared, and some up
+    /// to the limits of 64 and 128 bits.
+    fn number_strategy() -> impl Strategy<Value = N> {
+        prop_oneof![
+            6 => 1..4 as N,
+            3 => 1..30 as N,
+            1 => any::<u64>().prop_map(N::from),
+            1 => any::<N>(),
+        ]
+    }
+
+    fn input_strategy() -> impl Strategy<Value = Input> {
+        let operand = prop_oneof![Just(Op::Old), number_strategy().prop_map(Op::N)];
+        let ops = (operand, prop_oneof![Just(Op::Mul), Just(Op::Add)])
+            .prop_map(|(operand, op)| vec![Op::Old, op, operand]);
+        let monkey = (
+            prop::collection::vec(number_strategy(), 1..5),
+            ops,
+            number_strategy(),
+            any::<u8>(),
+            any::<u8>(),
+        );
+        prop::collection::vec(monkey, 2..5).prop_map(|monkeys| {
+            let count = monkeys.len() as u8;
+            (monkeys.into_iter().enumerate())
+                .map(
+                    |(id, (starting_items, ops, test_divisible, if_true, if_false))| Monkey {
+                        id: id as u8,
+                        starting_items,
+                        ops,
+                        test_divisible: test_divisible.max(1),
+                        if_true: if_true % count,
+                        if_false: if_false % count,
+                        inspection_count: 0,
+                    },
+                )
+                .collect()
+        })
+    }
+
+    /// Monkeys whose operations only ever shrink or slowly grow worry
+    /// levels, so that part 1 can not overflow.
+    fn small_input_strategy() -> impl Strategy<Value = Input> {
+        let ops = prop_oneof![
+            (1..10 as N).prop_map(|n| vec![Op::Old, Op::Add, Op::N(n)]),
+            Just(vec![Op::Old, Op::Mul, Op::N(2)]),
+        ];
+        let monkey = (
+            prop::collection::vec(1..100 as N, 1..5),
+            ops,
+            1..20 as N,
+            any::<u8>(),
+            any::<u8>(),
+        );
+        prop::collection::vec(monkey, 2..5).prop_map(|monkeys| {
+            let count = monkeys.len() as u8;
+            (monkeys.into_iter().enumerate())
+                .map(|(id, (starting_items, ops, test_divisible, t, f))| Monkey {
+                    id: id as u8,
+                    starting_items,
+                    ops,
+                    test_divisible,
+                    if_true: t % count,
+                    if_false: f % count,
+                    inspection_count: 0,
+                })
+                .collect()
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn _round_trip(input in input_strategy()) {
+            match parse(&to_text(&input)) {
+                Ok(parsed) => prop_assert_eq!(&input, &parsed),
+                // Large numbers are read fine but may not be solvable.
+                Err(e) => prop_assert!(
+                    e.message.contains("64 bits") || e.message.contains("overflow"),
+                    "{}",
+                    e
+                ),
+            }
+        }
+
+        #[test]
+        fn _accepted_inputs_solve(input in input_strategy()) {
+            if let Ok(input) = parse(&to_text(&input)) {
+                answer_part1(&input);
+                answer_part2(&input);
+            }
+        }
+
+        #[test]
+        fn _solvable(input in small_input_strategy()) {
+            let input = parse(&to_text(&input)).unwrap();
+            answer_part1(&input);
+            KeepAway::new(&input, Worry::Unrelieved, 100).run();
+        }
+
+        #[test]
+        fn _malformed_operation(
+            input in small_input_strategy(),
+            ops in prop::collection::vec(prop_oneof![Just("old"), Just("7"), Just("*"), Just("+")], 1..5),
+        ) {
+            let text = to_text(&input);
+            let line = text.lines().find(|l| l.starts_with("  Operation:")).unwrap();
+            let text = text.replacen(line, &format!("  Operation: new = {}", ops.join(" ")), 1);
+            let malformed = parse(&text)
+                .is_err_and(|e| e.message == "expected operands separated by '+' or '*'");
+            let well_formed = ops.len() % 2 == 1
+                && ops.iter().enumerate().all(|(i, op)| ["old", "7"].contains(op) == (i % 2 == 0));
+            prop_assert_eq!(well_formed, !malformed);
+        }
+    }
+}
@@ -0,0 +1,234 @@
+use aoc_common::image::Rgb;
+use aoc_common::{Grid, Maybe, ParseError, Rng, Solution, Vec2};
+
+/// The start, the end and the height of every square, `b'a'` to `b'z'`.
+pub type Input = (Vec2, Vec2, Grid<u8>);
+
+pub fn parse(s: &str) -> Result<Input, ParseError> {
+    let mut grid = Grid::from_char_map(s, |c| c)?;
+    if let Some((x, y)) = grid.position(|&c| !(c.is_ascii_lowercase() || c == b'S' || c == b'E')) {
+        let line = s.lines().nth(y).unwrap_or("");
+        return Err(ParseError::at(
+            s,
+            &line[x..],
+            "expected an elevation, 'S' or 'E'",
+        ));
+    }
+    let start = grid
+        .position(|&c| c == b'S')
+        .ok_or_else(|| ParseError::at_end(s, "no start position 'S'"))?;
+    let end = grid
+        .position(|&c| c == b'E')
+        .ok_or_else(|| ParseError::at_end(s, "no end position 'E'"))?;
+    grid[start] = b'a';
+    grid[end] = b'z';
+    let vec2 = |(x, y): (usize, usize)| Vec2::new(x as i32, y as i32);
+    Ok((vec2(start), vec2(end), grid))
+}
+
+/// Checks that there is exactly one start and one end, as `parse` goes with
+/// the first of each.
+pub fn check(s: &str, _input: &Input) -> Vec<ParseError> {
+    let mut problems = Vec::new();
+    for (marker, name) in [("S", "start"), ("E", "end")] {
+        for (i, _) in s.match_indices(marker).skip(1) {
+            problems.push(ParseError::at(
+                s,
+                &s[i..],
+                format!("more than one {} position '{}'", name, marker),
+            ));
+        }
+    }
+    problems
+}
+
+/// Neighbours of `current` that can be stepped to when climbing at most `UP`
+/// and descending at most `DOWN`.
+pub fn get_neighbors<const UP: u8, const DOWN: u8>(
+    grid: &Grid<u8>,
+    current: Vec2,
+) -> impl Iterator<Item = Vec2> + '_ {
+    let height = grid[current];
+    current
+        .neighbors4()
+        .filter(|&p| grid.bounds().contains(p))
+        .filter(move |&p| {
+            (grid[p] >= height && (grid[p] - height <= UP))
+                || (grid[p] < height && (height - grid[p]) <= DOWN)
+        })
+}
+
+/// A shortest path from the start to the end, both included.
+pub fn shortest_path((start, end, grid): &Input) -> Option<Vec<Vec2>> {
+    let move_cost = 1;
+    let res = pathfinding::directed::astar::astar(
+        start,
+        |current| get_neighbors::<1, 255>(grid, *current).map(|pos| (pos, move_cost)),
+        |pos| pos.manhattan(*end),
+        |n| n == end,
+    );
+    res.map(|x| x.0)
+}
+
+pub fn answer_part1(input: &Input) -> Option<usize> {
+    shortest_path(input).map(|path| path.len() - 1)
+}
+
+pub fn answer_part2((_, end, grid): &Input) -> Option<usize> {
+    let move_cost = 1;
+    let res = pathfinding::directed::astar::astar(
+        end,
+        |current| get_neighbors::<255, 1>(grid, *current).map(|pos| (pos, move_cost)),
+        |&pos| (b'z' - grid[pos]) as u32,
+        |&pos| grid[pos] == b'a',
+    );
+    res.map(|x| x.0.len() - 1)
+}
+
+/// A `size` by `size` heightmap rising from `S` in the top-left corner to `E`
+/// in the bottom-right one, with random dips along the way.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(2);
+    (0..size)
+        .map(|y| {
+            (0..size)
+                .map(|x| {
+                    if (x, y) == (0, 0) {
+                        return 'S';
+                    } else if (x, y) == (size - 1, size - 1) {
+                        return 'E';
+                    }
+                    let height = (x + y) * 26 / (2 * size - 1);
+                    let dip = usize::from(rng.chance(0.3));
+                    (b'a' + height.saturating_sub(dip) as u8) as char
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The heightmap from dark blue valleys to white peaks, with part 1's path in
+/// red.
+pub fn render(input: &Input) -> Grid<Rgb> {
+    let (_, _, grid) = input;
+    let mut image = grid.map(|&c| {
+        let t = (c - b'a') as u32 * 255 / 25;
+        [t as u8, t as u8, (64 + t * 191 / 255) as u8]
+    });
+    for pos in shortest_path(input).unwrap_or_default() {
+        image[pos] = [255, 0, 0];
+    }
+    image
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const INPUT: &'static str = include_str!("inputs");
+
+    type Input = Input;
+    type Output1 = Maybe<usize>;
+    type Output2 = Maybe<usize>;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        parse(s)
+    }
+
+    fn answer_part1(input: &Input) -> Maybe<usize> {
+        Maybe(answer_part1(input))
+    }
+
+    fn answer_part2(input: &Input) -> Maybe<usize> {
+        Maybe(answer_part2(input))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+
+    fn check(s: &str, input: &Input) -> Vec<ParseError> {
+        check(s, input)
+    }
+
+    fn render(input: &Input) -> Option<Grid<Rgb>> {
+        Some(render(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE_INPUT: &str = include_str!("../examples/example.txt");
+
+    #[test]
+    fn _part1() {
+        let inputs = parse(EXAMPLE_INPUT).unwrap();
+        assert_eq!(Some(31), answer_part1(&inputs));
+    }
+    #[test]
+    fn _part2() {
+        let inputs = parse(EXAMPLE_INPUT).unwrap();
+        assert_eq!(Some(29), answer_part2(&inputs));
+    }
+
+    #[test]
+    fn _render() {
+        let image = render(&parse(EXAMPLE_INPUT).unwrap());
+        assert_eq!((8, 5), (image.width(), image.height()));
+        let path = image.cells().iter().filter(|&&c| c == [255, 0, 0]).count();
+        assert_eq!(32, path);
+        assert_eq!([0, 0, 64], image[(0, 4)]);
+    }
+
+    #[test]
+    fn _check() {
+        assert!(check(EXAMPLE_INPUT, &parse(EXAMPLE_INPUT).unwrap()).is_empty());
+
+        let s = "SabE\nSEES";
+        let problems = check(s, &parse(s).unwrap());
+        let found: Vec<_> = problems.iter().map(|e| (e.line, e.column)).collect();
+        assert_eq!(vec![(2, 1), (2, 4), (2, 2), (2, 3)], found);
+    }
+
+    #[test]
+    fn _parse_error() {
+        let e = parse("Sab\nabc").unwrap_err();
+        assert_eq!((2, 4), (e.line, e.column));
+    }
+
+    fn to_text((start, end, grid): &Input) -> String {
+        let mut text = grid.map(|&c| c as char);
+        text[*start] = 'S';
+        text[*end] = 'E';
+        text.to_string()
+    }
+
+    fn input_strategy() -> impl Strategy<Value = Input> {
+        (2..8usize, 1..8usize)
+            .prop_flat_map(|(w, h)| {
+                let cells = prop::collection::vec(b'a'..=b'z', w * h);
+                (Just((w, h)), cells, 0..w * h, 0..w * h - 1)
+            })
+            .prop_map(|((w, h), cells, start, end)| {
+                // Skip over `start` so that the two never coincide.
+                let end = if end >= start { end + 1 } else { end };
+                let mut grid = Grid::from_vec(w, h, cells);
+                let (start, end) = ((start % w, start / w), (end % w, end / w));
+                grid[start] = b'a';
+                grid[end] = b'z';
+                let vec2 = |(x, y): (usize, usize)| Vec2::new(x as i32, y as i32);
+                (vec2(start), vec2(end), grid)
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn _round_trip(input in input_strategy()) {
+            prop_assert_eq!(&input, &parse(&to_text(&input)).unwrap());
+        }
+    }
+}
@@ -0,0 +1,387 @@
+extern crate nom;
+
+use std::fmt;
+
+use aoc_common::image::Rgb;
+use aoc_common::{Bounds, Grid, ParseError, Rng, Simulation, Solution, Vec2};
+use nom::{
+    bytes::complete::tag,
+    character::complete::digit1,
+    combinator as C,
+    error::{context, VerboseError},
+    multi::{self as M},
+    sequence::{self as S},
+    Finish,
+};
+
+type IResult<'a, T> = nom::IResult<&'a str, T, VerboseError<&'a str>>;
+
+pub type Input = Vec<Vec<Vec2>>;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Cell {
+    Empty,
+    Rock,
+    Sand,
+}
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Cell::Empty => '.',
+            Cell::Rock => '#',
+            Cell::Sand => 'o',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+/// Where sand enters the cave.
+const SOURCE: Vec2 = Vec2::new(500, 0);
+
+/// The cave filling up with sand, one grain per step. Without a floor it is
+/// done once a grain falls into the abyss, with one once the source is
+/// blocked.
+pub struct Cave {
+    grid: Grid<Cell>,
+    floor: bool,
+    grains: usize,
+    done: bool,
+}
+
+impl Cave {
+    pub fn new(inputs: &Input, floor: bool) -> Self {
+        let highest = Bounds::from_points(inputs.iter().flatten().copied())
+            .map_or(0, |bounds| bounds.max.y as usize);
+
+        // With a floor, sand comes to rest on the row above it.
+        let height = if floor { highest + 2 } else { highest + 1 };
+        let width = 1000;
+        let mut cave = Cave {
+            grid: Grid::new(width, height, Cell::Empty),
+            floor,
+            grains: 0,
+            done: false,
+        };
+        for line in inputs.iter() {
+            cave.add_line(line);
+        }
+        cave
+    }
+
+    /// Number of grains that have come to rest.
+    pub fn grains(&self) -> usize {
+        self.grains
+    }
+
+    fn add_line(&mut self, vertices: &[Vec2]) {
+        for segment in vertices.windows(2) {
+            // Segments run straight, as `check` makes sure, so this steps
+            // along one axis only.
+            let (from, to) = (segment[0], segment[1]);
+            let step = (to - from).signum();
+            for i in 0..=from.chebyshev(to) as i32 {
+                self.grid[from + step * i] = Cell::Rock;
+            }
+        }
+    }
+
+    /// Drops a grain from `from` and returns whether it came to rest above
+    /// the bottom row.
+    fn add_sand(&mut self, from: Vec2) -> bool {
+        if from.y as usize + 1 == self.grid.height() {
+            self.grid[from] = Cell::Sand;
+            return false;
+        }
+        for next in [
+            Vec2::DOWN,
+            Vec2::DOWN + Vec2::LEFT,
+            Vec2::DOWN + Vec2::RIGHT,
+        ] {
+            if self.grid[from + next] == Cell::Empty {
+                return self.add_sand(from + next);
+            }
+        }
+        self.grid[from] = Cell::Sand;
+        true
+    }
+}
+
+fn parse_i32(s: &str) -> IResult<'_, i32> {
+    C::map_res(digit1, |n: &str| n.parse::<i32>())(s)
+}
+
+fn parse_line(s: &str) -> IResult<'_, Vec<Vec2>> {
+    let parse_point = context(
+        "point",
+        C::map(
+            S::separated_pair(parse_i32, tag(","), parse_i32),
+            Vec2::from,
+        ),
+    );
+    context("rock path", M::separated_list1(tag(" -> "), parse_point))(s)
+}
+
+pub fn parse(s: &str) -> Result<Input, ParseError> {
+    s.lines()
+        .map(|line| {
+            C::all_consuming(parse_line)(line)
+                .finish()
+                .map(|(_, path)| path)
+                .map_err(|e| ParseError::from_nom(s, e))
+        })
+        .collect()
+}
+
+impl Simulation for Cave {
+    type State = Grid<Cell>;
+
+    fn step(&mut self) {
+        if self.done {
+            return;
+        }
+        if self.floor {
+            self.add_sand(SOURCE);
+            self.grains += 1;
+            self.done = self.grid[SOURCE] == Cell::Sand;
+        } else if self.add_sand(SOURCE) {
+            self.grains += 1;
+        } else {
+            self.done = true;
+        }
+    }
+
+    fn is_done(&self) -> bool {
+        self.done
+    }
+
+    fn snapshot(&self) -> Grid<Cell> {
+        self.grid.clone()
+    }
+}
+
+/// Checks that every x fits in the cave with room for sand to flow around it,
+/// that paths only run straight, and that the cave is shallow enough for the
+/// sand heaped on part 2's floor to stay within it.
+pub fn check(s: &str, input: &Input) -> Vec<ParseError> {
+    let mut problems = Vec::new();
+    let mut deepest = None;
+    for (line, path) in s.lines().zip(input) {
+        let points: Vec<&str> = line.split(" -> ").collect();
+        for (i, (&point, &v)) in points.iter().zip(path).enumerate() {
+            if !(1..=998).contains(&v.x) {
+                problems.push(ParseError::at(s, point, "expected x within 1..999"));
+            }
+            if i > 0 {
+                let from = path[i - 1];
+                if from.x != v.x && from.y != v.y {
+                    problems.push(ParseError::at(
+                        s,
+                        point,
+                        "expected a horizontal or vertical segment",
+                    ));
+                }
+            }
+            if deepest.is_none_or(|(_, y)| v.y > y) {
+                deepest = Some((point, v.y));
+            }
+        }
+    }
+    // With the floor, sand heaps up in a triangle as wide as it is high
+    // either side of the source.
+    if let Some((point, y)) = deepest {
+        if y + 2 > SOURCE.x {
+            problems.push(ParseError::at(
+                s,
+                point,
+                "too deep for the sand on the floor to fit in the cave",
+            ));
+        }
+    }
+    problems
+}
+
+pub fn answer_part1(inputs: &Input) -> usize {
+    let mut cave = Cave::new(inputs, false);
+    cave.run();
+    cave.grains()
+}
+
+pub fn answer_part2(inputs: &Input) -> usize {
+    let mut cave = Cave::new(inputs, true);
+    cave.run();
+    cave.grains()
+}
+
+/// `size` rock paths of up to 30 segments each. Everything stays less than 400
+/// units deep, so part 2's sand pile still fits in the 1000 wide cave.
+///
+/// No rock is placed in column 509 or in the ten rows below the source. Sand
+/// can never come to rest in that column without a floor, so the pile can not
+/// reach the source and part 1 always ends with sand falling into the abyss.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    const DRAIN_X: i64 = 509;
+    (0..size)
+        .map(|_| {
+            let (min_x, max_x) = if rng.chance(0.5) {
+                (120, DRAIN_X - 1)
+            } else {
+                (DRAIN_X + 1, 880)
+            };
+            let mut x = rng.range(min_x.max(400), max_x.min(600) + 1);
+            let mut y = rng.range(10, 399);
+            let mut path = vec![format!("{},{}", x, y)];
+            for segment in 0..rng.range(1, 31) {
+                if segment % 2 == 0 {
+                    x = (x + rng.range(-8, 9)).clamp(min_x, max_x);
+                } else {
+                    y = (y + rng.range(-8, 9)).clamp(10, 398);
+                }
+                path.push(format!("{},{}", x, y));
+            }
+            path.join(" -> ")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The cave once sand has piled up to the source, cropped to the columns the
+/// rocks and the pile take up.
+pub fn render(input: &Input) -> Grid<Rgb> {
+    let mut cave = Cave::new(input, true);
+    cave.run();
+    let grid = cave.snapshot();
+    let used = |x: usize| grid.column(x).any(|&c| c != Cell::Empty);
+    let x_min = (0..grid.width()).find(|&x| used(x)).unwrap_or(0);
+    let x_max = (0..grid.width()).rev().find(|&x| used(x)).unwrap_or(0);
+    let width = x_max - x_min + 1;
+    let cells = grid
+        .rows()
+        .flat_map(|row| &row[x_min..=x_max])
+        .map(|c| match c {
+            Cell::Empty => [20, 20, 30],
+            Cell::Rock => [120, 120, 120],
+            Cell::Sand => [230, 190, 90],
+        })
+        .collect();
+    Grid::from_vec(width, grid.height(), cells)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const INPUT: &'static str = include_str!("inputs");
+
+    type Input = Input;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        parse(s)
+    }
+
+    fn answer_part1(input: &Input) -> usize {
+        answer_part1(input)
+    }
+
+    fn answer_part2(input: &Input) -> usize {
+        answer_part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+
+    fn check(s: &str, input: &Input) -> Vec<ParseError> {
+        check(s, input)
+    }
+
+    fn render(input: &Input) -> Option<Grid<Rgb>> {
+        Some(render(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE_INPUT: &str = include_str!("../examples/example.txt");
+
+    #[test]
+    fn _part1() {
+        let inputs = parse(EXAMPLE_INPUT).unwrap();
+        assert_eq!(24, answer_part1(&inputs));
+    }
+    #[test]
+    fn _part2() {
+        let inputs = parse(EXAMPLE_INPUT).unwrap();
+        assert_eq!(93, answer_part2(&inputs));
+    }
+
+    #[test]
+    fn _cave_steps() {
+        let inputs = parse(EXAMPLE_INPUT).unwrap();
+        let mut cave = Cave::new(&inputs, false);
+        cave.step();
+        assert_eq!(Cell::Sand, cave.snapshot()[(500, 8)]);
+        assert_eq!(24, cave.run());
+        assert_eq!(24, cave.grains());
+    }
+
+    #[test]
+    fn _render() {
+        let image = render(&parse(EXAMPLE_INPUT).unwrap());
+        assert_eq!((21, 11), (image.width(), image.height()));
+        let sand = image
+            .cells()
+            .iter()
+            .filter(|&&c| c == [230, 190, 90])
+            .count();
+        assert_eq!(93, sand);
+    }
+
+    #[test]
+    fn _check() {
+        assert!(check(EXAMPLE_INPUT, &parse(EXAMPLE_INPUT).unwrap()).is_empty());
+
+        let s = "0,4 -> 0,6 -> 2,8\n500,10 -> 999,10\n10,499 -> 11,499";
+        let problems = check(s, &parse(s).unwrap());
+        let found: Vec<_> = problems.iter().map(|e| (e.line, e.column)).collect();
+        assert_eq!(vec![(1, 1), (1, 8), (1, 15), (2, 11), (3, 1)], found);
+    }
+
+    #[test]
+    fn _parse_error() {
+        let e = parse("498,4 -> 498,6\n503,4 -> x").unwrap_err();
+        assert_eq!((2, 6), (e.line, e.column));
+        let e = parse("498,4 -> 498,6\n503,x -> 1,2").unwrap_err();
+        assert_eq!(
+            (2, 5, "expected a number"),
+            (e.line, e.column, e.message.as_str())
+        );
+        assert_eq!(vec!["point", "rock path"], e.context);
+    }
+
+    fn to_text(input: &Input) -> String {
+        let paths: Vec<String> = input
+            .iter()
+            .map(|path| {
+                let points: Vec<String> = path.iter().map(|v| format!("{},{}", v.x, v.y)).collect();
+                points.join(" -> ")
+            })
+            .collect();
+        paths.join("\n")
+    }
+
+    proptest! {
+        #[test]
+        fn _round_trip(input in prop::collection::vec(
+            prop::collection::vec((0..1000, 0..1000).prop_map(Vec2::from), 1..6),
+            1..6,
+        )) {
+            prop_assert_eq!(&input, &parse(&to_text(&input)).unwrap());
+        }
+    }
+}
@@ -0,0 +1,179 @@
+extern crate nom;
+
+use std::collections::HashSet;
+
+use aoc_common::{Bounds, ParseError, Rng, Solution, Vec3};
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::digit1,
+    combinator as C,
+    error::{context, VerboseError},
+    sequence::{self as S},
+    Finish,
+};
+
+type IResult<'a, T> = nom::IResult<&'a str, T, VerboseError<&'a str>>;
+
+pub type Input = Vec<Vec3>;
+
+fn parse_i32(s: &str) -> IResult<'_, i32> {
+    C::map_res(digit1, |n: &str| n.parse::<i32>())(s)
+}
+
+fn parse_cube(s: &str) -> IResult<'_, Vec3> {
+    let coordinates = C::map(
+        S::tuple((parse_i32, tag(","), parse_i32, tag(","), parse_i32)),
+        |(x, _, y, _, z)| Vec3::new(x, y, z),
+    );
+    context("cube", coordinates)(s)
+}
+
+pub fn parse(s: &str) -> Result<Input, ParseError> {
+    s.lines()
+        .map(|line| {
+            C::all_consuming(parse_cube)(line)
+                .finish()
+                .map(|(_, v)| v)
+                .map_err(|e| ParseError::from_nom(s, e))
+        })
+        .collect()
+}
+
+pub fn answer_part1(inputs: &Input) -> usize {
+    let inputs: HashSet<Vec3> = inputs.iter().copied().collect();
+    inputs
+        .iter()
+        .map(|pos| pos.neighbors6().filter(|pos| !inputs.contains(pos)).count())
+        .sum()
+}
+
+/// Positions of air reachable from outside the droplet, within its bounding
+/// box grown by one in every direction.
+pub fn find_reachable(inputs: &Input) -> HashSet<Vec3> {
+    let droplets: HashSet<Vec3> = inputs.iter().copied().collect();
+    let Some(bounds) = Bounds::from_points(droplets.iter().copied()) else {
+        return HashSet::new();
+    };
+    let bounds = bounds.grow(1);
+    let mut outputs: HashSet<Vec3> = Default::default();
+    let mut prev_layer: HashSet<Vec3> = Default::default();
+    let mut next_layer: HashSet<Vec3> = Default::default();
+
+    next_layer.insert(bounds.min);
+
+    while !next_layer.is_empty() {
+        std::mem::swap(&mut prev_layer, &mut next_layer);
+        next_layer.clear();
+
+        for pos in prev_layer.iter().copied() {
+            for pos in pos.neighbors6() {
+                if bounds.contains(pos)
+                    && !prev_layer.contains(&pos)
+                    && !droplets.contains(&pos)
+                    && !outputs.contains(&pos)
+                {
+                    next_layer.insert(pos);
+                    outputs.insert(pos);
+                }
+            }
+        }
+    }
+
+    outputs
+}
+
+pub fn answer_part2(inputs: &Input) -> usize {
+    let reachable = find_reachable(inputs);
+    inputs
+        .iter()
+        .map(|pos| {
+            pos.neighbors6()
+                .filter(|pos| reachable.contains(pos))
+                .count()
+        })
+        .sum()
+}
+
+/// `size` distinct cubes packed into a box about twice their volume.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let side = ((size * 2) as f64).cbrt().ceil() as usize + 1;
+    let mut cells: Vec<usize> = (0..side * side * side).collect();
+    rng.shuffle(&mut cells);
+    cells[..size]
+        .iter()
+        .map(|&i| format!("{},{},{}", i % side, i / side % side, i / side / side))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    const INPUT: &'static str = include_str!("inputs");
+
+    type Input = Input;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        parse(s)
+    }
+
+    fn answer_part1(input: &Input) -> usize {
+        answer_part1(input)
+    }
+
+    fn answer_part2(input: &Input) -> usize {
+        answer_part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE_INPUT: &str = include_str!("../examples/example.txt");
+
+    #[test]
+    fn _part1() {
+        let inputs = parse(EXAMPLE_INPUT).unwrap();
+        assert_eq!(64, answer_part1(&inputs));
+    }
+    #[test]
+    fn _part2() {
+        let inputs = parse(EXAMPLE_INPUT).unwrap();
+        assert_eq!(58, answer_part2(&inputs));
+    }
+
+    #[test]
+    fn _parse_error() {
+        let e = parse("1,2,3\n1,2").unwrap_err();
+        assert_eq!((2, 4), (e.line, e.column));
+        assert_eq!(vec!["cube"], e.context);
+    }
+
+    fn to_text(input: &Input) -> String {
+        let cubes: Vec<String> = input
+            .iter()
+            .map(|v| format!("{},{},{}", v.x, v.y, v.z))
+            .collect();
+        cubes.join("\n")
+    }
+
+    proptest! {
+        #[test]
+        fn _round_trip(input in prop::collection::vec(
+            (0..100, 0..100, 0..100).prop_map(Vec3::from),
+            1..20,
+        )) {
+            prop_assert_eq!(&input, &parse(&to_text(&input)).unwrap());
+        }
+    }
+}
@@ -0,0 +1,604 @@
+use std::fmt;
+
+use aoc_common::{ParseError, Rng, Solution};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    pub const ALL: [Outcome; 3] = [Outcome::Lose, Outcome::Draw, Outcome::Win];
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+/// A move of a [`Game`], with the letters standing for it in the strategy
+/// guide and the points for picking it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GameMove {
+    pub name: String,
+    /// The letter in the opponent's column.
+    pub letter: char,
+    /// The letter in the second column when it is read as a move.
+    pub reply: char,
+    pub score: u32,
+}
+
+/// A cyclic-dominance game such as Rock-Paper-Scissors. The moves are listed
+/// in cycle order, and each one beats the moves an odd number of places
+/// before it, wrapping around. With an odd number of moves that makes every
+/// move beat exactly half of the others.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Game {
+    moves: Vec<GameMove>,
+    /// The letters for losing, drawing and winning in the second column.
+    outcome_letters: [char; 3],
+    /// The points for losing, drawing and winning a round.
+    outcome_scores: [u32; 3],
+}
+
+/// A line of the strategy guide. The second column is kept as a letter, as
+/// it means a move in part 1 and an outcome in part 2.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Round {
+    /// Index of the opponent's move in the game.
+    pub opponent: usize,
+    pub reply: char,
+}
+
+pub type Input = Vec<Round>;
+
+/// "A, B or C".
+fn one_of(letters: &[char]) -> String {
+    match letters {
+        [] => String::new(),
+        [letter] => letter.to_string(),
+        [init @ .., last] => {
+            let init: Vec<String> = init.iter().map(char::to_string).collect();
+            format!("{} or {}", init.join(", "), last)
+        }
+    }
+}
+
+fn has_duplicates(letters: &[char]) -> bool {
+    letters
+        .iter()
+        .enumerate()
+        .any(|(i, letter)| letters[..i].contains(letter))
+}
+
+impl Game {
+    pub fn new(
+        moves: Vec<GameMove>,
+        outcome_letters: [char; 3],
+        outcome_scores: [u32; 3],
+    ) -> Result<Game, String> {
+        if moves.len() < 3 || moves.len().is_multiple_of(2) {
+            return Err(format!(
+                "expected an odd number of moves, at least 3, but got {}",
+                moves.len()
+            ));
+        }
+        let letters: Vec<char> = moves.iter().map(|m| m.letter).collect();
+        let replies: Vec<char> = moves.iter().map(|m| m.reply).collect();
+        if has_duplicates(&letters) || has_duplicates(&replies) || has_duplicates(&outcome_letters)
+        {
+            return Err("expected every move and outcome to have its own letter".to_string());
+        }
+        Ok(Game {
+            moves,
+            outcome_letters,
+            outcome_scores,
+        })
+    }
+
+    /// The puzzle's game: A/B/C for the opponent, X/Y/Z for either the
+    /// player's move or the outcome, and 1, 2 and 3 points for the shapes.
+    pub fn rock_paper_scissors() -> Game {
+        let moves = [
+            ("Rock", 'A', 'X'),
+            ("Paper", 'B', 'Y'),
+            ("Scissors", 'C', 'Z'),
+        ];
+        Game::with_moves(&moves)
+    }
+
+    /// Rock-Paper-Scissors-Lizard-Spock, with A to E for the opponent, V to Z
+    /// for the player's move and X/Y/Z for the outcome.
+    pub fn rock_paper_scissors_lizard_spock() -> Game {
+        let moves = [
+            ("Rock", 'A', 'V'),
+            ("Paper", 'B', 'W'),
+            ("Scissors", 'C', 'X'),
+            ("Spock", 'D', 'Y'),
+            ("Lizard", 'E', 'Z'),
+        ];
+        Game::with_moves(&moves)
+    }
+
+    /// A game scored like the puzzle's, with moves worth 1, 2, 3 and so on.
+    fn with_moves(moves: &[(&str, char, char)]) -> Game {
+        let moves = moves
+            .iter()
+            .zip(1..)
+            .map(|(&(name, letter, reply), score)| GameMove {
+                name: name.to_string(),
+                letter,
+                reply,
+                score,
+            })
+            .collect();
+        Game::new(moves, ['X', 'Y', 'Z'], [0, 3, 6]).unwrap()
+    }
+
+    pub fn moves(&self) -> &[GameMove] {
+        &self.moves
+    }
+
+    pub fn outcome_letters(&self) -> [char; 3] {
+        self.outcome_letters
+    }
+
+    /// The outcome for the player of playing `me` against `opponent`.
+    pub fn outcome(&self, opponent: usize, me: usize) -> Outcome {
+        let n = self.moves.len();
+        match (me + n - opponent) % n {
+            0 => Outcome::Draw,
+            d if d % 2 == 1 => Outcome::Win,
+            _ => Outcome::Lose,
+        }
+    }
+
+    /// A move with the given outcome against `opponent`. Of several winning
+    /// or losing moves, this is the one next to it in the cycle.
+    pub fn reply_for(&self, opponent: usize, outcome: Outcome) -> usize {
+        let n = self.moves.len();
+        match outcome {
+            Outcome::Lose => (opponent + n - 1) % n,
+            Outcome::Draw => opponent,
+            Outcome::Win => (opponent + 1) % n,
+        }
+    }
+
+    /// Points for playing `me` against `opponent`.
+    pub fn score(&self, opponent: usize, me: usize) -> u32 {
+        self.moves[me].score + self.outcome_scores[self.outcome(opponent, me) as usize]
+    }
+
+    /// Points for getting `outcome` against `opponent`.
+    pub fn score2(&self, opponent: usize, outcome: Outcome) -> u32 {
+        self.score(opponent, self.reply_for(opponent, outcome))
+    }
+
+    /// The move `reply` stands for, if it is one.
+    pub fn reply_move(&self, reply: char) -> Option<usize> {
+        self.moves.iter().position(|m| m.reply == reply)
+    }
+
+    /// The outcome `reply` stands for, if it is one.
+    pub fn reply_outcome(&self, reply: char) -> Option<Outcome> {
+        let i = self.outcome_letters.iter().position(|&c| c == reply)?;
+        Some(Outcome::ALL[i])
+    }
+
+    /// The total score when the second column is read as moves, or `None` if
+    /// some letter in it is not a move.
+    pub fn total_part1(&self, rounds: &[Round]) -> Option<u32> {
+        rounds
+            .iter()
+            .map(|r| Some(self.score(r.opponent, self.reply_move(r.reply)?)))
+            .sum()
+    }
+
+    /// The total score when the second column is read as outcomes, or `None`
+    /// if some letter in it is not an outcome.
+    pub fn total_part2(&self, rounds: &[Round]) -> Option<u32> {
+        rounds
+            .iter()
+            .map(|r| Some(self.score2(r.opponent, self.reply_outcome(r.reply)?)))
+            .sum()
+    }
+
+    /// The letters allowed in the second column, which may be either moves
+    /// or outcomes.
+    fn reply_letters(&self) -> Vec<char> {
+        let mut letters: Vec<char> = self.moves.iter().map(|m| m.reply).collect();
+        for c in self.outcome_letters {
+            if !letters.contains(&c) {
+                letters.push(c);
+            }
+        }
+        letters
+    }
+
+    /// Parses a strategy guide written with this game's letters.
+    pub fn parse(&self, s: &str) -> Result<Input, ParseError> {
+        let letters: Vec<char> = self.moves.iter().map(|m| m.letter).collect();
+        let replies = self.reply_letters();
+        let single = |column: &str, allowed: &[char]| {
+            let mut chars = column.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) if allowed.contains(&c) => Some(c),
+                _ => None,
+            }
+        };
+        s.lines()
+            .map(|x| {
+                let (l, r) = x
+                    .split_once(" ")
+                    .ok_or_else(|| ParseError::at(s, x, "expected two columns"))?;
+                let opponent = single(l, &letters)
+                    .and_then(|c| letters.iter().position(|&l| l == c))
+                    .ok_or_else(|| {
+                        ParseError::at(s, l, format!("expected {}", one_of(&letters)))
+                    })?;
+                let reply = single(r, &replies).ok_or_else(|| {
+                    ParseError::at(s, r, format!("expected {}", one_of(&replies)))
+                })?;
+                Ok(Round { opponent, reply })
+            })
+            .collect()
+    }
+}
+
+pub fn parse(s: &str) -> Result<Input, ParseError> {
+    Game::rock_paper_scissors().parse(s)
+}
+
+/// Whether the second column stands for the player's moves, as in part 1, or
+/// for the outcomes, as in part 2.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Reading {
+    Moves,
+    Outcomes,
+}
+
+impl fmt::Display for Reading {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Reading::Moves => f.write_str("moves"),
+            Reading::Outcomes => f.write_str("outcomes"),
+        }
+    }
+}
+
+/// One way of reading the second column of a strategy guide.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mapping {
+    pub reading: Reading,
+    /// Each letter and the name of the move or outcome it stands for.
+    pub letters: Vec<(char, String)>,
+    /// Whether this is how the puzzle reads the letters.
+    pub standard: bool,
+    /// The guide's total score, or `None` if it uses a letter this reading
+    /// has no meaning for.
+    pub total: Option<u32>,
+}
+
+impl fmt::Display for Mapping {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let letters: Vec<String> = self
+            .letters
+            .iter()
+            .map(|(letter, name)| format!("{}={}", letter, name))
+            .collect();
+        f.write_str(&letters.join(" "))
+    }
+}
+
+/// Every ordering of `0..n`, in lexicographic order, so starting with the
+/// identity.
+fn permutations(n: usize) -> Vec<Vec<usize>> {
+    if n == 0 {
+        return vec![Vec::new()];
+    }
+    let mut orders = Vec::new();
+    for first in 0..n {
+        for rest in permutations(n - 1) {
+            let mut order = vec![first];
+            order.extend(rest.into_iter().map(|i| if i >= first { i + 1 } else { i }));
+            orders.push(order);
+        }
+    }
+    orders
+}
+
+/// The guide's total under every assignment of the game's reply letters to
+/// its moves, then under every assignment of its outcome letters to the
+/// outcomes.
+pub fn mappings(game: &Game, rounds: &[Round]) -> Vec<Mapping> {
+    let mut mappings = Vec::new();
+
+    let replies: Vec<char> = game.moves.iter().map(|m| m.reply).collect();
+    for order in permutations(replies.len()) {
+        let mut remapped = game.clone();
+        for (&letter, &m) in replies.iter().zip(&order) {
+            remapped.moves[m].reply = letter;
+        }
+        mappings.push(Mapping {
+            reading: Reading::Moves,
+            letters: (replies.iter().zip(&order))
+                .map(|(&letter, &m)| (letter, game.moves[m].name.clone()))
+                .collect(),
+            standard: order.iter().enumerate().all(|(i, &m)| i == m),
+            total: remapped.total_part1(rounds),
+        });
+    }
+
+    let letters = game.outcome_letters;
+    for order in permutations(letters.len()) {
+        let mut remapped = game.clone();
+        for (&letter, &o) in letters.iter().zip(&order) {
+            remapped.outcome_letters[o] = letter;
+        }
+        mappings.push(Mapping {
+            reading: Reading::Outcomes,
+            letters: (letters.iter().zip(&order))
+                .map(|(&letter, &o)| (letter, Outcome::ALL[o].to_string()))
+                .collect(),
+            standard: order.iter().enumerate().all(|(i, &o)| i == o),
+            total: remapped.total_part2(rounds),
+        });
+    }
+
+    mappings
+}
+
+/// The mappings with the highest and the lowest total, the first one found
+/// on a tie, or `None` if no mapping has a total.
+pub fn best_and_worst(mappings: &[Mapping]) -> Option<(&Mapping, &Mapping)> {
+    let scored = || mappings.iter().filter(|m| m.total.is_some());
+    let best = scored().rev().max_by_key(|m| m.total)?;
+    let worst = scored().min_by_key(|m| m.total)?;
+    Some((best, worst))
+}
+
+/// A table of the guide's total under every mapping, followed by the best
+/// and the worst of them.
+pub fn report(game: &Game, rounds: &[Round]) -> String {
+    let mappings = mappings(game, rounds);
+    let mut rows = vec![["Reading", "Mapping", "Total"].map(String::from)];
+    rows.extend(mappings.iter().map(|m| {
+        [
+            m.reading.to_string(),
+            m.to_string(),
+            m.total.map_or("-".to_string(), |total| total.to_string()),
+        ]
+    }));
+    let mut widths = [0; 3];
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+    let notes = std::iter::once(None).chain(mappings.iter().map(|m| {
+        m.standard.then_some(match m.reading {
+            Reading::Moves => "part 1",
+            Reading::Outcomes => "part 2",
+        })
+    }));
+    let mut lines: Vec<String> = rows
+        .iter()
+        .zip(notes)
+        .map(|(row, note)| {
+            let line = format!(
+                "{:<3$}  {:<4$}  {:>5$}",
+                row[0], row[1], row[2], widths[0], widths[1], widths[2]
+            );
+            match note {
+                Some(note) => format!("{}  ({})", line, note),
+                None => line,
+            }
+        })
+        .collect();
+
+    if let Some((best, worst)) = best_and_worst(&mappings) {
+        lines.push(String::new());
+        for (label, m) in [("Best: ", best), ("Worst:", worst)] {
+            lines.push(format!(
+                "{} {} as {}, {}",
+                label,
+                m,
+                m.reading,
+                m.total.unwrap_or_default()
+            ));
+        }
+    }
+    lines.join("\n")
+}
+
+// In Rock-Paper-Scissors X, Y and Z are both moves and outcomes, so every
+// parsed guide has a total for both parts.
+pub fn answer_part1(inputs: &Input) -> usize {
+    Game::rock_paper_scissors()
+        .total_part1(inputs)
+        .expect("X, Y and Z are all moves") as usize
+}
+
+pub fn answer_part2(inputs: &Input) -> usize {
+    Game::rock_paper_scissors()
+        .total_part2(inputs)
+        .expect("X, Y and Z are all outcomes") as usize
+}
+
+/// `size` rounds.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            format!(
+                "{} {}",
+                rng.pick(&["A", "B", "C"]),
+                rng.pick(&["X", "Y", "Z"])
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    const INPUT: &'static str = include_str!("inputs");
+
+    type Input = Input;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        parse(s)
+    }
+
+    fn answer_part1(input: &Input) -> usize {
+        answer_part1(input)
+    }
+
+    fn answer_part2(input: &Input) -> usize {
+        answer_part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+
+    fn report(input: &Input) -> Option<String> {
+        Some(report(&Game::rock_paper_scissors(), input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE_INPUT: &str = include_str!("../examples/example.txt");
+
+    #[test]
+    fn _part1() {
+        let inputs = parse(EXAMPLE_INPUT).unwrap();
+        assert_eq!(15, answer_part1(&inputs));
+    }
+    #[test]
+    fn _part2() {
+        let inputs = parse(EXAMPLE_INPUT).unwrap();
+        assert_eq!(12, answer_part2(&inputs));
+    }
+
+    #[test]
+    fn _parse_error() {
+        let e = parse("A Y\nB Q").unwrap_err();
+        assert_eq!((2, 3), (e.line, e.column));
+        assert_eq!("expected X, Y or Z", e.message);
+        let e = parse("A Y\nBB X").unwrap_err();
+        assert_eq!(
+            (2, 1, "expected A, B or C"),
+            (e.line, e.column, e.message.as_str())
+        );
+    }
+
+    #[test]
+    fn _rules() {
+        let game = Game::rock_paper_scissors_lizard_spock();
+        let find = |name: &str| game.moves().iter().position(|m| m.name == name).unwrap();
+        let beats = [
+            ("Scissors", "Paper"),
+            ("Paper", "Rock"),
+            ("Rock", "Lizard"),
+            ("Lizard", "Spock"),
+            ("Spock", "Scissors"),
+            ("Scissors", "Lizard"),
+            ("Lizard", "Paper"),
+            ("Paper", "Spock"),
+            ("Spock", "Rock"),
+            ("Rock", "Scissors"),
+        ];
+        for (winner, loser) in beats {
+            assert_eq!(Outcome::Win, game.outcome(find(loser), find(winner)));
+            assert_eq!(Outcome::Lose, game.outcome(find(winner), find(loser)));
+        }
+        for opponent in 0..5 {
+            for outcome in Outcome::ALL {
+                let me = game.reply_for(opponent, outcome);
+                assert_eq!(outcome, game.outcome(opponent, me));
+            }
+        }
+
+        let rounds = game.parse("A V\nD Z\nE X").unwrap();
+        assert_eq!(Some(4 + 11 + 9), game.total_part1(&rounds));
+        assert_eq!(None, game.total_part2(&rounds));
+        assert!(Game::new(game.moves()[..4].to_vec(), ['X', 'Y', 'Z'], [0, 3, 6]).is_err());
+    }
+
+    #[test]
+    fn _mappings() {
+        let game = Game::rock_paper_scissors();
+        let rounds = parse(EXAMPLE_INPUT).unwrap();
+        let mappings = mappings(&game, &rounds);
+        assert_eq!(12, mappings.len());
+        let standard: Vec<_> = mappings.iter().filter(|m| m.standard).collect();
+        assert_eq!(
+            vec![(Reading::Moves, Some(15)), (Reading::Outcomes, Some(12))],
+            standard
+                .iter()
+                .map(|m| (m.reading, m.total))
+                .collect::<Vec<_>>()
+        );
+        let (best, worst) = best_and_worst(&mappings).unwrap();
+        assert_eq!("X=Scissors Y=Paper Z=Rock", best.to_string());
+        assert_eq!((Reading::Moves, Some(24)), (best.reading, best.total));
+        assert_eq!("X=Rock Y=Scissors Z=Paper", worst.to_string());
+        assert_eq!(Some(6), worst.total);
+
+        // V and W are only moves, so no outcome reading covers this guide.
+        let game = Game::rock_paper_scissors_lizard_spock();
+        let rounds = game.parse("A V\nB Z").unwrap();
+        let mappings = super::mappings(&game, &rounds);
+        assert_eq!(120 + 6, mappings.len());
+        assert!(mappings
+            .iter()
+            .all(|m| m.total.is_some() == (m.reading == Reading::Moves)));
+    }
+
+    #[test]
+    fn _report() {
+        let report = report(&Game::rock_paper_scissors(), &parse(EXAMPLE_INPUT).unwrap());
+        let lines: Vec<&str> = report.lines().collect();
+        assert_eq!("Reading   Mapping                    Total", lines[0]);
+        assert_eq!(
+            "moves     X=Rock Y=Paper Z=Scissors     15  (part 1)",
+            lines[1]
+        );
+        assert_eq!(
+            "outcomes  X=Lose Y=Draw Z=Win           12  (part 2)",
+            lines[7]
+        );
+        assert_eq!(
+            "Worst: X=Rock Y=Scissors Z=Paper as moves, 6",
+            lines[lines.len() - 1]
+        );
+    }
+
+    fn to_text(input: &Input) -> String {
+        let letters = ['A', 'B', 'C'];
+        let rounds: Vec<String> = input
+            .iter()
+            .map(|r| format!("{} {}", letters[r.opponent], r.reply))
+            .collect();
+        rounds.join("\n")
+    }
+
+    proptest! {
+        #[test]
+        fn _round_trip(input in prop::collection::vec((0..3usize, prop::sample::select(vec!['X', 'Y', 'Z']))
+            .prop_map(|(opponent, reply)| Round { opponent, reply }), 1..10)) {
+            prop_assert_eq!(&input, &parse(&to_text(&input)).unwrap());
+        }
+    }
+}
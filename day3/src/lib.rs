@@ -0,0 +1,171 @@
+use aoc_common::{ParseError, Rng, Solution};
+
+pub type Input = Vec<Vec<u8>>;
+
+fn prio(c: u8) -> u8 {
+    if c > 96 {
+        c - 96
+    } else {
+        c - 38
+    }
+}
+
+pub fn parse(s: &str) -> Result<Input, ParseError> {
+    s.split("\n")
+        .map(|line| {
+            if let Some(i) = line.find(|c: char| !c.is_ascii_alphabetic()) {
+                return Err(ParseError::at(s, &line[i..], "expected a letter"));
+            }
+            Ok(line.as_bytes().iter().copied().map(prio).collect())
+        })
+        .collect()
+}
+
+fn intersection(comp_a: &[u8], comp_b: &[u8]) -> Vec<u8> {
+    let mut vals = [0u8; 53];
+    for &a in comp_a {
+        vals[a as usize] = 1u8;
+    }
+    for &b in comp_b {
+        if vals[b as usize] == 1 {
+            vals[b as usize] = 2u8;
+        }
+    }
+    let mut res = Vec::new();
+    for (i, &v) in vals.iter().enumerate().skip(1) {
+        if v == 2 {
+            res.push(i as u8)
+        }
+    }
+    res
+}
+
+pub fn answer_part1(inputs: &Input) -> usize {
+    inputs
+        .iter()
+        .map(|line| {
+            let i = line.len() / 2;
+            intersection(&line[..i], &line[i..])
+                .into_iter()
+                .map(|n| n as u32)
+                .sum::<u32>()
+        })
+        .sum::<u32>() as usize
+}
+
+pub fn answer_part2(inputs: &Input) -> usize {
+    let mut groups: Vec<[&[u8]; 3]> = Vec::new();
+    for i in 0..(inputs.len() / 3) {
+        groups.push([&inputs[i * 3], &inputs[i * 3 + 1], &inputs[i * 3 + 2]]);
+    }
+    groups
+        .into_iter()
+        .map(|[a, b, c]| {
+            intersection(&intersection(a, b), c)
+                .into_iter()
+                .map(|n| n as u32)
+                .sum::<u32>()
+        })
+        .sum::<u32>() as usize
+}
+
+/// `size` groups of three rucksacks. Each group draws from disjoint item pools
+/// apart from its badge, so both compartments of a rucksack share exactly one
+/// item and the three rucksacks share exactly the badge.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut items: Vec<u8> = (b'a'..=b'z').chain(b'A'..=b'Z').collect();
+    let mut lines = Vec::with_capacity(size * 3);
+    for _ in 0..size {
+        rng.shuffle(&mut items);
+        let (badge, pools) = items.split_first().unwrap();
+        for pool in pools.chunks(17) {
+            let (shared, rest) = pool.split_first().unwrap();
+            let (left_pool, right_pool) = rest.split_at(rest.len() / 2);
+            let n = rng.index(12) + 4;
+            let mut left = vec![*shared, *badge];
+            let mut right = vec![*shared];
+            left.extend((2..n).map(|_| *rng.pick(left_pool)));
+            right.extend((1..n).map(|_| *rng.pick(right_pool)));
+            rng.shuffle(&mut left);
+            rng.shuffle(&mut right);
+            left.append(&mut right);
+            lines.push(String::from_utf8(left).unwrap());
+        }
+    }
+    lines.join("\n")
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    const INPUT: &'static str = include_str!("inputs");
+
+    type Input = Input;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        parse(s)
+    }
+
+    fn answer_part1(input: &Input) -> usize {
+        answer_part1(input)
+    }
+
+    fn answer_part2(input: &Input) -> usize {
+        answer_part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE_INPUT: &str = include_str!("../examples/example.txt");
+
+    #[test]
+    fn _prio() {
+        assert_eq!(1, prio(b'a'));
+        assert_eq!(26, prio(b'z'));
+        assert_eq!(27, prio(b'A'));
+        assert_eq!(52, prio(b'Z'));
+    }
+    #[test]
+    fn _part1() {
+        let inputs = parse(EXAMPLE_INPUT).unwrap();
+        assert_eq!(157, answer_part1(&inputs));
+    }
+    #[test]
+    fn _part2() {
+        let inputs = parse(EXAMPLE_INPUT).unwrap();
+        assert_eq!(70, answer_part2(&inputs));
+    }
+
+    #[test]
+    fn _parse_error() {
+        let e = parse("vJrw\nab1d").unwrap_err();
+        assert_eq!((2, 3), (e.line, e.column));
+    }
+
+    fn to_text(input: &Input) -> String {
+        let item = |p: u8| if p <= 26 { b'a' + p - 1 } else { b'A' + p - 27 } as char;
+        let lines: Vec<String> = input
+            .iter()
+            .map(|line| line.iter().map(|&p| item(p)).collect())
+            .collect();
+        lines.join("\n")
+    }
+
+    proptest! {
+        #[test]
+        fn _round_trip(input in prop::collection::vec(prop::collection::vec(1u8..=52, 1..20), 1..10)) {
+            prop_assert_eq!(&input, &parse(&to_text(&input)).unwrap());
+        }
+    }
+}
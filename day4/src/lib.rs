@@ -0,0 +1,125 @@
+use aoc_common::{ParseError, Rng, Solution};
+
+pub type Input = Vec<((i32, i32), (i32, i32))>;
+
+pub fn parse(s: &str) -> Result<Input, ParseError> {
+    let range = |r: &str| {
+        let (start, end) = r
+            .split_once("-")
+            .ok_or_else(|| ParseError::at(s, r, "expected a range like '2-4'"))?;
+        let n = |n: &str| {
+            n.parse::<i32>()
+                .map_err(|_| ParseError::at(s, n, "expected a section id"))
+        };
+        Ok((n(start)?, n(end)?))
+    };
+    s.split("\n")
+        .map(|line| {
+            let (r1, r2) = line
+                .split_once(",")
+                .ok_or_else(|| ParseError::at(s, line, "expected two ranges"))?;
+            Ok((range(r1)?, range(r2)?))
+        })
+        .collect()
+}
+
+fn has_full_intersect((s1, e1): (i32, i32), (s2, e2): (i32, i32)) -> bool {
+    (s1 >= s2 && e1 <= e2) || (s2 >= s1 && e2 <= e1)
+}
+
+fn has_no_intersect((s1, e1): (i32, i32), (s2, e2): (i32, i32)) -> bool {
+    e1 < s2 || e2 < s1
+}
+
+pub fn answer_part1(inputs: &Input) -> usize {
+    inputs
+        .iter()
+        .filter(|(r1, r2)| has_full_intersect(*r1, *r2))
+        .count()
+}
+
+pub fn answer_part2(inputs: &Input) -> usize {
+    inputs
+        .iter()
+        .filter(|(r1, r2)| !has_no_intersect(*r1, *r2))
+        .count()
+}
+
+/// `size` pairs of section ranges.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut range = || {
+        let start = rng.range(1, 100);
+        format!("{}-{}", start, start + rng.range(0, 30))
+    };
+    (0..size)
+        .map(|_| format!("{},{}", range(), range()))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    const INPUT: &'static str = include_str!("inputs");
+
+    type Input = Input;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        parse(s)
+    }
+
+    fn answer_part1(input: &Input) -> usize {
+        answer_part1(input)
+    }
+
+    fn answer_part2(input: &Input) -> usize {
+        answer_part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE_INPUT: &str = include_str!("../examples/example.txt");
+
+    #[test]
+    fn _part1() {
+        let inputs = parse(EXAMPLE_INPUT).unwrap();
+        assert_eq!(2, answer_part1(&inputs));
+    }
+    #[test]
+    fn _part2() {
+        let inputs = parse(EXAMPLE_INPUT).unwrap();
+        assert_eq!(4, answer_part2(&inputs));
+    }
+
+    #[test]
+    fn _parse_error() {
+        let e = parse("2-4,6-8\n2-3;4-5").unwrap_err();
+        assert_eq!((2, 1), (e.line, e.column));
+    }
+
+    fn to_text(input: &Input) -> String {
+        let pairs: Vec<String> = input
+            .iter()
+            .map(|((a, b), (c, d))| format!("{}-{},{}-{}", a, b, c, d))
+            .collect();
+        pairs.join("\n")
+    }
+
+    proptest! {
+        #[test]
+        fn _round_trip(input in prop::collection::vec(((0..100, 0..100), (0..100, 0..100)), 1..10)) {
+            prop_assert_eq!(&input, &parse(&to_text(&input)).unwrap());
+        }
+    }
+}
@@ -0,0 +1,440 @@
+use aoc_common::{ParseError, Rng, Simulation, Solution};
+
+/// Crate stacks, bottom crate first, indexed by stack id minus one.
+pub type Stacks = [Vec<u8>; u8::MAX as usize];
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Input {
+    pub stacks: Stacks,
+    /// `(count, from, to)`, with 1-based stack ids.
+    pub moves: Vec<(u8, u8, u8)>,
+}
+
+fn perform_move(stacks: &mut Stacks, n: u8, from: u8, to: u8) {
+    let from = &mut stacks[(from - 1) as usize];
+    let n = n as usize;
+
+    let mut to_move: Vec<_> = from.drain((from.len() - n)..).collect();
+    stacks[(to - 1) as usize].append(&mut to_move);
+}
+
+fn extract_container(s: &str) -> Option<u8> {
+    let s = s.as_bytes();
+    if s[0] == b'[' && s[2] == b']' {
+        Some(s[1])
+    } else {
+        None
+    }
+}
+
+fn parse_move<'a>(s: &'a str, line: &'a str) -> Result<(u8, u8, u8), ParseError> {
+    let expect_word = |line: &'a str, word: &str| {
+        line.strip_prefix(word)
+            .ok_or_else(|| ParseError::at(s, line, format!("expected '{}'", word)))
+    };
+    let number = |line: &'a str, what: &str| -> Result<(u8, &'a str), ParseError> {
+        let (n, rest) = line.split_once(' ').unwrap_or((line, &line[line.len()..]));
+        let n = n
+            .parse::<u8>()
+            .map_err(|_| ParseError::at(s, n, format!("expected {}", what)))?;
+        Ok((n, rest))
+    };
+    let stack_id = |line: &'a str| -> Result<(u8, &'a str), ParseError> {
+        let (id, rest) = number(line, "a stack id")?;
+        if id == 0 {
+            return Err(ParseError::at(s, line, "stack ids start at 1"));
+        }
+        Ok((id, rest))
+    };
+
+    let (count, line) = number(expect_word(line, "move ")?, "a crate count")?;
+    let (from, line) = stack_id(expect_word(line, "from ")?)?;
+    let (to, line) = stack_id(expect_word(line, "to ")?)?;
+    if !line.is_empty() {
+        return Err(ParseError::at(s, line, "unexpected trailing input"));
+    }
+    Ok((count, from, to))
+}
+
+pub fn parse(s: &str) -> Result<Input, ParseError> {
+    let (part1, part2) = s
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::at_end(s, "expected a blank line between stacks and moves"))?;
+
+    let mut stacks: Stacks = array_init::array_init(|_| Vec::<u8>::new());
+
+    for line in part1.lines().rev().skip(1) {
+        let mut line = line;
+        let mut i = 0;
+        while !line.is_empty() {
+            let (container, rest_line) = line
+                .split_at_checked(3)
+                .ok_or_else(|| ParseError::at(s, line, "expected '[X]' or three spaces"))?;
+            if let Some(label) = extract_container(container) {
+                let stack = stacks
+                    .get_mut(i)
+                    .ok_or_else(|| ParseError::at(s, container, "too many stacks"))?;
+                stack.push(label);
+            }
+            line = rest_line.strip_prefix(' ').unwrap_or(rest_line);
+            i += 1;
+        }
+    }
+
+    let moves: Vec<(u8, u8, u8)> = part2
+        .lines()
+        .map(|line| parse_move(s, line))
+        .collect::<Result<_, _>>()?;
+
+    // The crane can not take crates that are not there, so replay the stack
+    // heights to reject such moves up front.
+    let mut heights: Vec<usize> = stacks.iter().map(Vec::len).collect();
+    for (line, &(count, from, to)) in part2.lines().zip(&moves) {
+        let (count, from, to) = (count as usize, (from - 1) as usize, (to - 1) as usize);
+        if count > heights[from] {
+            let count_text = line.split(' ').nth(1).unwrap_or(line);
+            return Err(ParseError::at(
+                s,
+                count_text,
+                format!("stack {} only holds {} crates", from + 1, heights[from]),
+            ));
+        }
+        heights[from] -= count;
+        heights[to] += count;
+    }
+
+    Ok(Input { stacks, moves })
+}
+
+/// Checks that the stacks are numbered 1 to at most 9 in order, that every
+/// crate is labelled with a single letter and sits on a numbered stack, and
+/// that no move refers to a missing stack. Moves taking more crates than
+/// their stack holds are already rejected by `parse`.
+pub fn check(s: &str, input: &Input) -> Vec<ParseError> {
+    let mut problems = Vec::new();
+    let (drawing, procedure) = s.split_once("\n\n").unwrap_or((s, ""));
+
+    let ids_line = drawing.lines().last().unwrap_or(drawing);
+    let ids: Vec<&str> = ids_line.split_whitespace().collect();
+    for (i, id) in ids.iter().enumerate() {
+        if *id != (i + 1).to_string() {
+            problems.push(ParseError::at(
+                s,
+                id,
+                format!("expected stack id {}", i + 1),
+            ));
+        }
+    }
+    if !(1..=9).contains(&ids.len()) {
+        problems.push(ParseError::at(
+            s,
+            ids_line,
+            format!("expected 1 to 9 stacks, found {}", ids.len()),
+        ));
+    }
+    let stack_count = ids.len().min(9);
+
+    for line in drawing.lines().rev().skip(1) {
+        let cells = (0..line.len())
+            .step_by(4)
+            .filter_map(|i| line.get(i..i + 3));
+        for (i, cell) in cells.enumerate() {
+            if cell == "   " {
+                continue;
+            }
+            match extract_container(cell) {
+                Some(label) if !label.is_ascii_alphabetic() => problems.push(ParseError::at(
+                    s,
+                    &cell[1..],
+                    "expected a single letter crate label",
+                )),
+                Some(_) if i >= stack_count => {
+                    problems.push(ParseError::at(s, cell, "crate is not on a numbered stack"))
+                }
+                Some(_) => {}
+                None => problems.push(ParseError::at(s, cell, "expected '[X]' or three spaces")),
+            }
+        }
+    }
+
+    for (line, &(_, from, to)) in procedure.lines().zip(&input.moves) {
+        let words: Vec<&str> = line.split(' ').collect();
+        let missing = |id: u8| id as usize > stack_count;
+        if missing(from) {
+            problems.push(ParseError::at(s, words[3], format!("no stack {}", from)));
+        }
+        if missing(to) {
+            problems.push(ParseError::at(s, words[5], format!("no stack {}", to)));
+        }
+    }
+    problems
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CraneModel {
+    /// Moves crates one at a time.
+    CrateMover9000,
+    /// Moves several crates at once, keeping their order.
+    CrateMover9001,
+}
+
+/// The crane rearranging the stacks, one line of the procedure per step.
+pub struct Crane<'a> {
+    model: CraneModel,
+    stacks: Stacks,
+    moves: &'a [(u8, u8, u8)],
+    next: usize,
+}
+
+impl<'a> Crane<'a> {
+    pub fn new(input: &'a Input, model: CraneModel) -> Self {
+        Crane {
+            model,
+            stacks: input.stacks.clone(),
+            moves: &input.moves,
+            next: 0,
+        }
+    }
+
+    /// The crate on top of each stack.
+    pub fn tops(&self) -> String {
+        let mut s = String::with_capacity(self.stacks.len());
+        for v in self.stacks.iter() {
+            if let Some(&c) = v.last() {
+                s.push(c as char);
+            }
+        }
+        s
+    }
+}
+
+impl Simulation for Crane<'_> {
+    type State = Stacks;
+
+    fn step(&mut self) {
+        let Some(&(n, from, to)) = self.moves.get(self.next) else {
+            return;
+        };
+        match self.model {
+            CraneModel::CrateMover9000 => {
+                for _ in 0..n {
+                    perform_move(&mut self.stacks, 1, from, to);
+                }
+            }
+            CraneModel::CrateMover9001 => perform_move(&mut self.stacks, n, from, to),
+        }
+        self.next += 1;
+    }
+
+    fn is_done(&self) -> bool {
+        self.next == self.moves.len()
+    }
+
+    fn snapshot(&self) -> Stacks {
+        self.stacks.clone()
+    }
+}
+
+pub fn answer_part1(inputs: &Input) -> String {
+    let mut crane = Crane::new(inputs, CraneModel::CrateMover9000);
+    crane.run();
+    crane.tops()
+}
+
+pub fn answer_part2(inputs: &Input) -> String {
+    let mut crane = Crane::new(inputs, CraneModel::CrateMover9001);
+    crane.run();
+    crane.tops()
+}
+
+/// Nine stacks and `size` moves. Moves are replayed while generating so that
+/// none of them takes more crates than its stack holds.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    const STACKS: usize = 9;
+    let mut heights: Vec<usize> = (0..STACKS).map(|_| rng.index(40)).collect();
+    let tallest = heights.iter().copied().max().unwrap_or(0);
+
+    let mut lines = Vec::with_capacity(tallest + size + 2);
+    for level in (0..tallest).rev() {
+        let row: Vec<String> = heights
+            .iter()
+            .map(|&h| {
+                if level < h {
+                    format!("[{}]", (b'A' + rng.index(26) as u8) as char)
+                } else {
+                    "   ".to_string()
+                }
+            })
+            .collect();
+        lines.push(row.join(" "));
+    }
+    let ids: Vec<String> = (1..=STACKS).map(|i| format!(" {} ", i)).collect();
+    lines.push(ids.join(" "));
+    lines.push(String::new());
+
+    for _ in 0..size {
+        let non_empty: Vec<usize> = (0..STACKS).filter(|&i| heights[i] > 0).collect();
+        if non_empty.is_empty() {
+            break;
+        }
+        let from = *rng.pick(&non_empty);
+        let to = (from + 1 + rng.index(STACKS - 1)) % STACKS;
+        let count = 1 + rng.index(heights[from].min(u8::MAX as usize));
+        heights[from] -= count;
+        heights[to] += count;
+        lines.push(format!("move {} from {} to {}", count, from + 1, to + 1));
+    }
+    lines.join("\n")
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    const INPUT: &'static str = include_str!("inputs");
+
+    type Input = Input;
+    type Output1 = String;
+    type Output2 = String;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        parse(s)
+    }
+
+    fn answer_part1(input: &Input) -> String {
+        answer_part1(input)
+    }
+
+    fn answer_part2(input: &Input) -> String {
+        answer_part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+
+    fn check(s: &str, input: &Input) -> Vec<ParseError> {
+        check(s, input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE_INPUT: &str = include_str!("../examples/example.txt");
+
+    #[test]
+    fn _part1() {
+        let inputs = parse(EXAMPLE_INPUT).unwrap();
+        assert_eq!("CMZ".to_string(), answer_part1(&inputs));
+    }
+    #[test]
+    fn _part2() {
+        let inputs = parse(EXAMPLE_INPUT).unwrap();
+        assert_eq!("MCD".to_string(), answer_part2(&inputs));
+    }
+
+    #[test]
+    fn _crane_steps() {
+        let inputs = parse(EXAMPLE_INPUT).unwrap();
+        let states = Crane::new(&inputs, CraneModel::CrateMover9000).record();
+        assert_eq!(5, states.len());
+        assert_eq!(b"ZND", &states[1][0][..]);
+        assert!(states[2][0].is_empty());
+    }
+
+    #[test]
+    fn _parse_error() {
+        let e = parse("    [D]\n[N] [C]\n 1   2\n\nmove 1 from 2 to 1\nmove x from 1 to 2")
+            .unwrap_err();
+        assert_eq!((6, 6), (e.line, e.column));
+        let e = parse("    [D]\n[N] [C]\n 1   2\n\nmove 1 from 2 to 1\nmove 3 from 1 to 2")
+            .unwrap_err();
+        assert_eq!(
+            (6, 6, "stack 1 only holds 2 crates"),
+            (e.line, e.column, e.message.as_str())
+        );
+    }
+
+    #[test]
+    fn _check() {
+        assert!(check(EXAMPLE_INPUT, &parse(EXAMPLE_INPUT).unwrap()).is_empty());
+
+        let s = "[1] [B] [C]\n 1   3\n\nmove 1 from 1 to 3\nmove 1 from 2 to 1";
+        let problems = check(s, &parse(s).unwrap());
+        let found: Vec<_> = problems.iter().map(|e| (e.line, e.column)).collect();
+        assert_eq!(vec![(2, 6), (1, 2), (1, 9), (4, 18)], found);
+    }
+
+    fn to_text(input: &Input) -> String {
+        let count = input
+            .stacks
+            .iter()
+            .rposition(|s| !s.is_empty())
+            .map_or(1, |i| i + 1);
+        let stacks = &input.stacks[..count];
+        let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+        let mut lines: Vec<String> = (0..height)
+            .rev()
+            .map(|level| {
+                let cells: Vec<String> = stacks
+                    .iter()
+                    .map(|s| match s.get(level) {
+                        Some(&c) => format!("[{}]", c as char),
+                        None => "   ".to_string(),
+                    })
+                    .collect();
+                cells.join(" ")
+            })
+            .collect();
+        let ids: Vec<String> = (1..=count).map(|i| format!(" {} ", i)).collect();
+        lines.push(ids.join(" "));
+        lines.push(String::new());
+        lines.extend(
+            (input.moves.iter()).map(|(n, from, to)| format!("move {} from {} to {}", n, from, to)),
+        );
+        lines.join("\n")
+    }
+
+    fn input_strategy() -> impl Strategy<Value = Input> {
+        let stacks = prop::collection::vec(prop::collection::vec(b'A'..=b'Z', 0..6), 1..=9);
+        let moves = prop::collection::vec((any::<u8>(), 1u8..=9, 1u8..=9), 1..10);
+        (stacks, moves).prop_map(|(stacks, mut moves)| {
+            let mut all: Stacks = array_init::array_init(|_| Vec::new());
+            for (i, s) in stacks.into_iter().enumerate() {
+                all[i] = s;
+            }
+            // Only take crates that are there, as `parse` requires.
+            let mut heights: Vec<usize> = all.iter().map(Vec::len).collect();
+            for (count, from, to) in moves.iter_mut() {
+                let (from, to) = (*from as usize - 1, *to as usize - 1);
+                *count %= heights[from] as u8 + 1;
+                heights[from] -= *count as usize;
+                heights[to] += *count as usize;
+            }
+            Input { stacks: all, moves }
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn _round_trip(input in input_strategy()) {
+            prop_assert_eq!(&input, &parse(&to_text(&input)).unwrap());
+            answer_part1(&input);
+            answer_part2(&input);
+        }
+
+        #[test]
+        fn _overdrawn_move(input in input_strategy(), from in 1u8..=9, to in 1u8..=9) {
+            let mut crane = Crane::new(&input, CraneModel::CrateMover9001);
+            crane.run();
+            let held = crane.snapshot()[from as usize - 1].len();
+            let text = format!("{}\nmove {} from {} to {}", to_text(&input), held + 1, from, to);
+            let e = parse(&text).unwrap_err();
+            prop_assert_eq!(text.lines().count(), e.line);
+            prop_assert!(e.message.contains("only holds"));
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use aoc_common::{Maybe, ParseError, Rng, Solution};
+
+pub type Input = String;
+
+pub fn parse(s: &str) -> Result<Input, ParseError> {
+    Ok(s.to_string())
+}
+
+fn all_distinct<const N: usize>(buf: &[u8; N]) -> bool {
+    for i in 0..N {
+        for j in (i + 1)..N {
+            if buf[i] == buf[j] {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+fn index_of_consecutive_distinct<const N: usize>(s: &str) -> Option<usize> {
+    let s = s.as_bytes();
+    let mut buf: [u8; N] = [0u8; N];
+    // A stream shorter than a marker has none.
+    buf.copy_from_slice(s.get(..N)?);
+    if all_distinct(&buf) {
+        return Some(N + 1);
+    }
+
+    for i in 0..(s.len() - N) {
+        for j in 0..(N - 1) {
+            buf[j] = buf[j + 1];
+        }
+        buf[N - 1] = s[i + N];
+        if all_distinct(&buf) {
+            return Some(i + N + 1);
+        }
+    }
+    None
+}
+pub fn answer_part1(inputs: &Input) -> Option<usize> {
+    index_of_consecutive_distinct::<4>(inputs)
+}
+
+pub fn answer_part2(inputs: &Input) -> Option<usize> {
+    index_of_consecutive_distinct::<14>(inputs)
+}
+
+/// A stream of `size` characters drawn from only three letters, so that no
+/// marker can appear before the fourteen distinct letters at its very end.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut s: String = (0..size)
+        .map(|_| (b'a' + rng.index(3) as u8) as char)
+        .collect();
+    let mut marker: Vec<u8> = (b'a'..=b'z').collect();
+    rng.shuffle(&mut marker);
+    s.extend(marker[..14].iter().map(|&c| c as char));
+    s
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    const INPUT: &'static str = include_str!("inputs");
+
+    type Input = Input;
+    type Output1 = Maybe<usize>;
+    type Output2 = Maybe<usize>;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        parse(s)
+    }
+
+    fn answer_part1(input: &Input) -> Maybe<usize> {
+        Maybe(answer_part1(input))
+    }
+
+    fn answer_part2(input: &Input) -> Maybe<usize> {
+        Maybe(answer_part2(input))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn _part1() {
+        assert_eq!(
+            Some(7),
+            answer_part1(&parse(include_str!("../examples/example1.txt")).unwrap())
+        );
+        assert_eq!(
+            Some(5),
+            answer_part1(&parse(include_str!("../examples/example2.txt")).unwrap())
+        );
+        assert_eq!(
+            Some(6),
+            answer_part1(&parse(include_str!("../examples/example3.txt")).unwrap())
+        );
+        assert_eq!(
+            Some(10),
+            answer_part1(&parse(include_str!("../examples/example4.txt")).unwrap())
+        );
+        assert_eq!(
+            Some(11),
+            answer_part1(&parse(include_str!("../examples/example5.txt")).unwrap())
+        );
+    }
+    #[test]
+    fn _part2() {
+        assert_eq!(
+            Some(19),
+            answer_part2(&parse(include_str!("../examples/example1.txt")).unwrap())
+        );
+        assert_eq!(
+            Some(23),
+            answer_part2(&parse(include_str!("../examples/example2.txt")).unwrap())
+        );
+        assert_eq!(
+            Some(23),
+            answer_part2(&parse(include_str!("../examples/example3.txt")).unwrap())
+        );
+        assert_eq!(
+            Some(29),
+            answer_part2(&parse(include_str!("../examples/example4.txt")).unwrap())
+        );
+        assert_eq!(
+            Some(26),
+            answer_part2(&parse(include_str!("../examples/example5.txt")).unwrap())
+        );
+    }
+
+    #[test]
+    fn _short_stream() {
+        assert_eq!(None, answer_part1(&parse("").unwrap()));
+        assert_eq!(None, answer_part1(&parse("abc").unwrap()));
+        assert_eq!(None, answer_part2(&parse("abcdefghijklm").unwrap()));
+    }
+
+    proptest! {
+        #[test]
+        fn _round_trip(input in "[a-z]{1,50}") {
+            prop_assert_eq!(&input, &parse(&input).unwrap());
+            answer_part1(&input);
+            answer_part2(&input);
+        }
+    }
+}
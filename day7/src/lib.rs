@@ -0,0 +1,488 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use aoc_common::{ParseError, Rng, Solution};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Com {
+    Ls { output: Vec<LsNode> },
+    Cd { arg: String },
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum LsNode {
+    File { name: String, size: usize },
+    Dir { name: String },
+}
+
+/// A node of the filesystem inferred from a transcript. A directory holds its
+/// total size, including everything below it, and its entries by name.
+#[derive(Debug)]
+pub enum FsNode {
+    File(usize),
+    Dir((usize, HashMap<String, FsNode>)),
+}
+
+impl FsNode {
+    pub fn size(&self) -> usize {
+        match self {
+            Self::Dir((size, _)) => *size,
+            Self::File(size) => *size,
+        }
+    }
+}
+
+pub type Input = Vec<Com>;
+
+/// Why a transcript can not be followed, with the index of the offending
+/// command.
+#[derive(Debug, PartialEq, Eq)]
+pub enum FsError {
+    /// `cd ..` in the root directory.
+    AboveRoot { command: usize },
+    /// `cd` into something listed as a file.
+    NotADirectory { command: usize, name: String },
+    /// `cd` into a directory that has not been listed.
+    NotListed { command: usize, name: String },
+}
+
+impl FsError {
+    pub fn command(&self) -> usize {
+        match self {
+            FsError::AboveRoot { command }
+            | FsError::NotADirectory { command, .. }
+            | FsError::NotListed { command, .. } => *command,
+        }
+    }
+}
+
+impl fmt::Display for FsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FsError::AboveRoot { .. } => write!(f, "can not leave the root directory"),
+            FsError::NotADirectory { name, .. } => write!(f, "'{}' is a file", name),
+            FsError::NotListed { name, .. } => write!(f, "'{}' has not been listed", name),
+        }
+    }
+}
+
+impl std::error::Error for FsError {}
+
+type Entries = HashMap<String, FsNode>;
+
+/// Puts the current directory back into its parent and makes the parent
+/// current, or returns false in the root directory.
+fn leave(ancestors: &mut Vec<(String, Entries)>, current: &mut Entries) -> bool {
+    let Some((name, mut parent)) = ancestors.pop() else {
+        return false;
+    };
+    parent.insert(name, FsNode::Dir((0, std::mem::take(current))));
+    *current = parent;
+    true
+}
+
+/// Rebuilds the filesystem explored by `commands` and returns its root.
+///
+/// Fails if the transcript leaves the root directory or enters a directory
+/// that it hasn't listed.
+///
+/// ```
+/// use day7::FsNode;
+///
+/// let input = day7::parse(
+///     "$ cd /\n$ ls\ndir a\n14848514 b.txt\n$ cd a\n$ ls\ndir e\n29116 f\n$ cd e\n$ ls\n584 i",
+/// )
+/// .unwrap();
+/// let FsNode::Dir((size, entries)) = day7::infer_filesystem(&input).unwrap() else {
+///     unreachable!()
+/// };
+/// assert_eq!(14878214, size);
+/// assert_eq!(29700, entries["a"].size());
+/// ```
+pub fn infer_filesystem(commands: &[Com]) -> Result<FsNode, FsError> {
+    // The directories above the current one, innermost last, each with the
+    // name of its child on the way down.
+    let mut ancestors: Vec<(String, Entries)> = vec![];
+    let mut node: Entries = Default::default();
+    for (command, com) in commands.iter().enumerate() {
+        match com {
+            Com::Ls { output } => {
+                for out in output {
+                    match out {
+                        LsNode::File { name, size } => {
+                            node.insert(name.to_string(), FsNode::File(*size));
+                        }
+                        LsNode::Dir { name } => {
+                            node.insert(name.to_string(), FsNode::Dir(Default::default()));
+                        }
+                    }
+                }
+            }
+            Com::Cd { arg } if arg == "/" => while leave(&mut ancestors, &mut node) {},
+            Com::Cd { arg } if arg == ".." => {
+                if !leave(&mut ancestors, &mut node) {
+                    return Err(FsError::AboveRoot { command });
+                }
+            }
+            Com::Cd { arg } => {
+                let name = arg.to_string();
+                match node.remove(arg) {
+                    Some(FsNode::Dir((_, entries))) => {
+                        ancestors.push((name, std::mem::replace(&mut node, entries)));
+                    }
+                    Some(FsNode::File(_)) => {
+                        return Err(FsError::NotADirectory { command, name });
+                    }
+                    None => return Err(FsError::NotListed { command, name }),
+                }
+            }
+        }
+    }
+    while leave(&mut ancestors, &mut node) {}
+
+    let mut root = FsNode::Dir((0, node));
+    cache_size_of(&mut root);
+    Ok(root)
+}
+
+fn cache_size_of(n: &mut FsNode) -> usize {
+    match n {
+        FsNode::File(size) => *size,
+        FsNode::Dir((ref mut size, ns)) => {
+            // Saturates rather than overflowing on absurd file sizes.
+            let n = ns
+                .iter_mut()
+                .map(|(_, n)| cache_size_of(n))
+                .fold(0, usize::saturating_add);
+            *size = n;
+            n
+        }
+    }
+}
+
+fn parse_cd(s: &str) -> Option<(&str, Com)> {
+    if !s.starts_with("$ cd ") {
+        return None;
+    }
+    let s = &s["$ cd ".len()..];
+    let (arg, rest) = s.split_once("\n").unwrap_or((s, &s[s.len()..]));
+
+    Some((
+        rest,
+        Com::Cd {
+            arg: arg.to_string(),
+        },
+    ))
+}
+fn parse_ls<'a>(input: &str, s: &'a str) -> Result<Option<(&'a str, Com)>, ParseError> {
+    if !s.starts_with("$ ls\n") {
+        return Ok(None);
+    }
+    let s = &s["$ ls\n".len()..];
+    let mut nodes: Vec<LsNode> = Vec::new();
+    let mut s = s;
+
+    while !s.is_empty() && !s.starts_with("$") {
+        let (a, rest_s) = s
+            .split_once(" ")
+            .ok_or_else(|| ParseError::at(input, s, "expected 'dir <name>' or '<size> <name>'"))?;
+        let (b, rest_s) = rest_s
+            .split_once("\n")
+            .unwrap_or((rest_s, &rest_s[rest_s.len()..]));
+        if a == "dir" {
+            nodes.push(LsNode::Dir {
+                name: b.to_string(),
+            });
+        } else {
+            let size = a
+                .parse::<usize>()
+                .map_err(|_| ParseError::at(input, a, "expected 'dir' or a file size"))?;
+            nodes.push(LsNode::File {
+                name: b.to_string(),
+                size,
+            });
+        }
+        s = rest_s;
+    }
+
+    Ok(Some((s, Com::Ls { output: nodes })))
+}
+
+/// Parses a transcript, rejecting one that `infer_filesystem` can not
+/// follow.
+pub fn parse(s: &str) -> Result<Input, ParseError> {
+    let input = s;
+    let mut coms: Vec<Com> = Vec::new();
+    // Where each command starts, to point errors at.
+    let mut starts: Vec<&str> = Vec::new();
+    let mut s = s;
+    while !s.is_empty() {
+        starts.push(s);
+        let com = match parse_cd(s) {
+            Some(com) => Some(com),
+            None => parse_ls(input, s)?,
+        };
+        if let Some((next_s, com)) = com {
+            coms.push(com);
+            s = next_s;
+        } else {
+            return Err(ParseError::at(input, s, "expected '$ cd <dir>' or '$ ls'"));
+        }
+    }
+    if let Err(e) = infer_filesystem(&coms) {
+        let at = starts[e.command()];
+        // Point at the argument of the `cd`.
+        return Err(ParseError::at(input, &at["$ cd ".len()..], e.to_string()));
+    }
+    Ok(coms)
+}
+
+fn answer_part1_(input: FsNode) -> usize {
+    match input {
+        FsNode::Dir((dir_size, sub_nodes)) => {
+            let dir_size = if dir_size <= 100_000 { dir_size } else { 0 };
+            let sub_sum = sub_nodes.into_values().map(answer_part1_).sum::<usize>();
+            dir_size + sub_sum
+        }
+        _ => 0,
+    }
+}
+pub fn answer_part1(inputs: &Input) -> usize {
+    let root = infer_filesystem(inputs).expect("parse only accepts followable transcripts");
+    answer_part1_(root)
+}
+
+const FS_TOTAL_SPACE: usize = 70_000_000;
+const FS_REQUIRED_SPACE: usize = 30_000_000;
+
+fn answer_part2_(input: FsNode, space_to_free: usize) -> usize {
+    match input {
+        FsNode::Dir((dir_size, sub_nodes)) => {
+            let dir_size = if dir_size > space_to_free {
+                dir_size
+            } else {
+                usize::MAX
+            };
+            let sub_min = sub_nodes
+                .into_values()
+                .map(|n| answer_part2_(n, space_to_free))
+                .min()
+                .unwrap_or(usize::MAX);
+            usize::min(dir_size, sub_min)
+        }
+        _ => usize::MAX,
+    }
+}
+pub fn answer_part2(inputs: &Input) -> usize {
+    let root = infer_filesystem(inputs).expect("parse only accepts followable transcripts");
+    let available_space = FS_TOTAL_SPACE.saturating_sub(root.size());
+    let space_to_free = FS_REQUIRED_SPACE.saturating_sub(available_space);
+    answer_part2_(root, space_to_free)
+}
+
+/// A transcript exploring a filesystem of `size` directories in a random tree.
+/// File sizes are scaled so that the disk is between 45M and 65M full, which
+/// part 2 relies on.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut children: Vec<Vec<usize>> = vec![Vec::new(); size];
+    for dir in 1..size {
+        children[rng.index(dir)].push(dir);
+    }
+    let mut files: Vec<Vec<u64>> = (0..size)
+        .map(|_| {
+            (0..rng.index(4))
+                .map(|_| rng.next_u64() % 1000 + 1)
+                .collect()
+        })
+        .collect();
+    if files.iter().all(|f| f.is_empty()) {
+        files[0].push(1);
+    }
+    let total: u64 = files.iter().flatten().sum();
+    let target = rng.range(45_000_000, 65_000_000) as u64;
+    for size in files.iter_mut().flatten() {
+        *size = (*size * target / total).max(1);
+    }
+
+    let mut lines = vec!["$ cd /".to_string()];
+    // Directories still to visit, with `None` marking a return to the parent.
+    let mut stack = vec![Some(0)];
+    while let Some(entry) = stack.pop() {
+        let Some(dir) = entry else {
+            lines.push("$ cd ..".to_string());
+            continue;
+        };
+        if dir != 0 {
+            lines.push(format!("$ cd d{}", dir));
+        }
+        lines.push("$ ls".to_string());
+        lines.extend(children[dir].iter().map(|c| format!("dir d{}", c)));
+        lines.extend(
+            (files[dir].iter().enumerate()).map(|(i, size)| format!("{} f{}.txt", size, i)),
+        );
+        for &child in children[dir].iter().rev() {
+            stack.push(None);
+            stack.push(Some(child));
+        }
+    }
+    lines.join("\n")
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    const INPUT: &'static str = include_str!("inputs");
+
+    type Input = Input;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        parse(s)
+    }
+
+    fn answer_part1(input: &Input) -> usize {
+        answer_part1(input)
+    }
+
+    fn answer_part2(input: &Input) -> usize {
+        answer_part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE_INPUT: &str = include_str!("../examples/example.txt");
+
+    #[test]
+    fn _part1() {
+        let inputs = parse(EXAMPLE_INPUT).unwrap();
+        assert_eq!(95437, answer_part1(&inputs));
+    }
+    #[test]
+    fn _part2() {
+        let inputs = parse(EXAMPLE_INPUT).unwrap();
+        assert_eq!(24933642, answer_part2(&inputs));
+    }
+
+    #[test]
+    fn _parse_error() {
+        let e = parse("$ cd /\n$ ls\n12 a\n$ rm a").unwrap_err();
+        assert_eq!((4, 1), (e.line, e.column));
+
+        let error = |s: &str| {
+            let e = parse(s).unwrap_err();
+            (e.line, e.column, e.message)
+        };
+        assert_eq!(
+            (2, 6, "can not leave the root directory".to_string()),
+            error("$ cd /\n$ cd ..")
+        );
+        assert_eq!(
+            (3, 6, "'a' is a file".to_string()),
+            error("$ ls\n12 a\n$ cd a")
+        );
+        assert_eq!(
+            (3, 6, "'b' has not been listed".to_string()),
+            error("$ ls\ndir a\n$ cd b")
+        );
+    }
+
+    #[test]
+    fn _deep_transcript() {
+        let s = "$ cd /\n$ ls\ndir a\n1 x\n$ cd a\n$ ls\ndir b\n10 y\n$ cd b\n$ ls\ndir c\n100 z\n$ cd c\n$ ls\n1000 w";
+        let FsNode::Dir((size, root)) = infer_filesystem(&parse(s).unwrap()).unwrap() else {
+            unreachable!()
+        };
+        assert_eq!(1111, size);
+        assert_eq!(1110, root["a"].size());
+        let FsNode::Dir((_, a)) = &root["a"] else {
+            unreachable!()
+        };
+        assert_eq!(1100, a["b"].size());
+        assert_eq!(vec!["a", "x"], {
+            let mut names: Vec<_> = root.keys().collect();
+            names.sort();
+            names
+        });
+
+        // `cd /` climbs all the way back up.
+        let s = format!("{}\n$ cd /\n$ ls\n5 v", s);
+        assert_eq!(1116, infer_filesystem(&parse(&s).unwrap()).unwrap().size());
+    }
+
+    fn to_text(input: &Input) -> String {
+        let coms: Vec<String> = input
+            .iter()
+            .map(|com| match com {
+                Com::Cd { arg } => format!("$ cd {}", arg),
+                Com::Ls { output } => {
+                    let nodes: Vec<String> = output
+                        .iter()
+                        .map(|node| match node {
+                            LsNode::Dir { name } => format!("dir {}", name),
+                            LsNode::File { name, size } => format!("{} {}", size, name),
+                        })
+                        .collect();
+                    format!("$ ls\n{}", nodes.join("\n"))
+                }
+            })
+            .collect();
+        coms.join("\n")
+    }
+
+    fn com_strategy() -> impl Strategy<Value = Com> {
+        let name = "[a-z]{1,8}(\\.[a-z]{1,3})?";
+        let node = prop_oneof![
+            name.prop_map(|name| LsNode::Dir { name }),
+            (name, any::<usize>()).prop_map(|(name, size)| LsNode::File { name, size }),
+        ];
+        prop_oneof![
+            prop_oneof![Just("/".to_string()), Just("..".to_string()), name.boxed()]
+                .prop_map(|arg| Com::Cd { arg }),
+            prop::collection::vec(node, 1..5).prop_map(|output| Com::Ls { output }),
+        ]
+    }
+
+    /// Commands that `infer_filesystem` can follow, with any `cd` it can not
+    /// follow replaced by `cd /`.
+    fn transcript_strategy() -> impl Strategy<Value = Input> {
+        prop::collection::vec(com_strategy(), 1..10).prop_map(|coms| {
+            let mut transcript = Vec::new();
+            for com in coms {
+                transcript.push(com);
+                if infer_filesystem(&transcript).is_err() {
+                    transcript.pop();
+                    transcript.push(Com::Cd {
+                        arg: "/".to_string(),
+                    });
+                }
+            }
+            transcript
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn _round_trip(input in transcript_strategy()) {
+            prop_assert_eq!(&input, &parse(&to_text(&input)).unwrap());
+            answer_part1(&input);
+            answer_part2(&input);
+        }
+
+        #[test]
+        fn _unfollowable(input in prop::collection::vec(com_strategy(), 1..10)) {
+            let followable = infer_filesystem(&input).is_ok();
+            prop_assert_eq!(followable, parse(&to_text(&input)).is_ok());
+        }
+    }
+}
@@ -0,0 +1,202 @@
+use aoc_common::image::Rgb;
+use aoc_common::{Grid, ParseError, Rng, Solution};
+
+pub type Input = Grid<u8>;
+
+pub fn parse(s: &str) -> Result<Input, ParseError> {
+    let grid = Grid::from_char_map(s, |c| c)?;
+    if let Some((x, y)) = grid.position(|c| !c.is_ascii_digit()) {
+        let line = s.lines().nth(y).unwrap_or("");
+        return Err(ParseError::at(s, &line[x..], "expected a tree height"));
+    }
+    Ok(grid.map(|c| c - 48))
+}
+
+fn is_visible(input: &Input, x: usize, y: usize) -> bool {
+    let n = input[(x, y)];
+    let row = input.row(y);
+    let left = row[..x].iter().all(|m| *m < n);
+    let right = row[(x + 1)..].iter().all(|m| *m < n);
+    let top = (0..y).all(|y| input[(x, y)] < n);
+    let bottom = ((y + 1)..input.height()).all(|y| input[(x, y)] < n);
+
+    left || right || top || bottom
+}
+
+pub fn answer_part1(inputs: &Input) -> usize {
+    // Trees on the edge look out over nothing, so they are always visible,
+    // even in forests one tree wide.
+    inputs
+        .positions()
+        .filter(|&(x, y)| is_visible(inputs, x, y))
+        .count()
+}
+
+fn scenic_score(input: &Input, x: usize, y: usize) -> usize {
+    let n = input[(x, y)];
+    let row = input.row(y);
+    let left = row[..x]
+        .iter()
+        .enumerate()
+        .rev()
+        .filter(|(_, n2)| **n2 >= n)
+        .map(|(x2, _)| x.abs_diff(x2))
+        .next()
+        .unwrap_or(x);
+    let right = row[(x + 1)..]
+        .iter()
+        .enumerate()
+        .map(|(i, n)| (i + x + 1, n))
+        .filter(|(_, n2)| **n2 >= n)
+        .map(|(x2, _)| x.abs_diff(x2))
+        .next()
+        .unwrap_or(input.width() - x - 1);
+    let top = (0..y)
+        .rev()
+        .filter(|y| input[(x, *y)] >= n)
+        .map(|y2| y.abs_diff(y2))
+        .next()
+        .unwrap_or(y);
+    let bottom = ((y + 1)..input.height())
+        .filter(|y| input[(x, *y)] >= n)
+        .map(|y2| y.abs_diff(y2))
+        .next()
+        .unwrap_or(input.height() - y - 1);
+
+    top * left * bottom * right
+}
+
+pub fn answer_part2(inputs: &Input) -> usize {
+    // Trees on the edge see nothing in one direction and score zero.
+    (1..inputs.height().saturating_sub(1))
+        .flat_map(|y| (1..inputs.width().saturating_sub(1)).map(move |x| (x, y)))
+        .map(|(x, y)| scenic_score(inputs, x, y))
+        .max()
+        .unwrap_or(0)
+}
+
+/// A `size` by `size` forest.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| (b'0' + rng.index(10) as u8) as char)
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Trees in shades of green by height, with those hidden from every edge
+/// greyed out.
+pub fn render(input: &Input) -> Grid<Rgb> {
+    let cells = input
+        .positions()
+        .map(|(x, y)| {
+            let shade = 75 + input[(x, y)] * 20;
+            if is_visible(input, x, y) {
+                [0, shade, 0]
+            } else {
+                [shade / 2, shade / 2, shade / 2]
+            }
+        })
+        .collect();
+    Grid::from_vec(input.width(), input.height(), cells)
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    const INPUT: &'static str = include_str!("inputs");
+
+    type Input = Input;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        parse(s)
+    }
+
+    fn answer_part1(input: &Input) -> usize {
+        answer_part1(input)
+    }
+
+    fn answer_part2(input: &Input) -> usize {
+        answer_part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+
+    fn render(input: &Input) -> Option<Grid<Rgb>> {
+        Some(render(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE_INPUT: &str = include_str!("../examples/example.txt");
+
+    #[test]
+    fn _part1() {
+        let inputs = parse(EXAMPLE_INPUT).unwrap();
+        assert_eq!(21, answer_part1(&inputs));
+    }
+    #[test]
+    fn _part2() {
+        let inputs = parse(EXAMPLE_INPUT).unwrap();
+        assert_eq!(8, answer_part2(&inputs));
+    }
+
+    #[test]
+    fn _render() {
+        let image = render(&parse(EXAMPLE_INPUT).unwrap());
+        assert_eq!((5, 5), (image.width(), image.height()));
+        assert_eq!([0, 135, 0], image[(0, 0)]);
+        assert_eq!([47, 47, 47], image[(3, 1)]);
+    }
+
+    #[test]
+    fn _parse_error() {
+        let e = parse("123\n4a6").unwrap_err();
+        assert_eq!((2, 2), (e.line, e.column));
+    }
+
+    #[test]
+    fn _thin_forests() {
+        let empty = parse("").unwrap();
+        assert_eq!((0, 0), (answer_part1(&empty), answer_part2(&empty)));
+        let column = parse("3\n1\n4").unwrap();
+        assert_eq!((3, 0), (answer_part1(&column), answer_part2(&column)));
+    }
+
+    fn grid_strategy(sizes: std::ops::Range<usize>) -> impl Strategy<Value = Grid<u8>> {
+        (sizes.clone(), sizes).prop_flat_map(|(w, h)| {
+            prop::collection::vec(0u8..10, w * h).prop_map(move |cells| Grid::from_vec(w, h, cells))
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn _round_trip(input in grid_strategy(1..8)) {
+            let text = input.map(|&h| (b'0' + h) as char).to_string();
+            prop_assert_eq!(&input, &parse(&text).unwrap());
+        }
+
+        #[test]
+        fn _edges_visible(input in grid_strategy(0..6)) {
+            let (w, h) = (input.width(), input.height());
+            let thin = w <= 2 || h <= 2;
+            let edge = if thin { w * h } else { 2 * w + 2 * h - 4 };
+            prop_assert!(answer_part1(&input) >= edge);
+            if thin {
+                prop_assert_eq!(0, answer_part2(&input));
+            }
+        }
+    }
+}
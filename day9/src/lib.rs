@@ -0,0 +1,277 @@
+use std::collections::HashSet;
+
+use aoc_common::image::Rgb;
+use aoc_common::{Bounds, Grid, ParseError, Rng, Simulation, Solution, Vec2};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum D {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+pub type Input = Vec<(D, u8)>;
+
+pub fn parse(s: &str) -> Result<Input, ParseError> {
+    let input = s;
+    s.lines()
+        .map(|s| {
+            let (dir, n) = s
+                .split_once(" ")
+                .ok_or_else(|| ParseError::at(input, s, "expected a direction and a count"))?;
+            let dir = match dir {
+                "L" => D::Left,
+                "R" => D::Right,
+                "U" => D::Up,
+                "D" => D::Down,
+                _ => return Err(ParseError::at(input, dir, "expected L, R, U or D")),
+            };
+            let n = n
+                .parse::<u8>()
+                .map_err(|_| ParseError::at(input, n, "expected a step count"))?;
+            Ok((dir, n))
+        })
+        .collect()
+}
+
+impl D {
+    /// One square in this direction.
+    pub fn offset(self) -> Vec2 {
+        match self {
+            D::Left => Vec2::LEFT,
+            D::Right => Vec2::RIGHT,
+            D::Up => Vec2::UP,
+            D::Down => Vec2::DOWN,
+        }
+    }
+}
+
+/// A rope of knots pulled along by its head, moving the head one square per
+/// step.
+pub struct Rope<'a> {
+    motions: &'a [(D, u8)],
+    motion: usize,
+    moved: u8,
+    knots: Vec<Vec2>,
+    visited: HashSet<Vec2>,
+}
+
+impl<'a> Rope<'a> {
+    /// Panics if `knots` is zero.
+    pub fn new(motions: &'a Input, knots: usize) -> Self {
+        assert!(knots > 0, "a rope needs a head");
+        let mut rope = Rope {
+            motions,
+            motion: 0,
+            moved: 0,
+            knots: vec![Vec2::ZERO; knots],
+            visited: HashSet::from([Vec2::ZERO]),
+        };
+        rope.skip_finished_motions();
+        rope
+    }
+
+    /// Number of positions the tail has visited so far.
+    pub fn visited(&self) -> usize {
+        self.visited.len()
+    }
+
+    /// Positions the tail has visited so far.
+    pub fn trail(&self) -> &HashSet<Vec2> {
+        &self.visited
+    }
+
+    fn skip_finished_motions(&mut self) {
+        while self
+            .motions
+            .get(self.motion)
+            .is_some_and(|&(_, n)| self.moved == n)
+        {
+            self.motion += 1;
+            self.moved = 0;
+        }
+    }
+}
+
+impl Simulation for Rope<'_> {
+    /// Positions of the knots, head first.
+    type State = Vec<Vec2>;
+
+    fn step(&mut self) {
+        let Some(&(dir, _)) = self.motions.get(self.motion) else {
+            return;
+        };
+        let knots = &mut self.knots;
+        let prev_tail = knots[knots.len() - 1];
+        knots[0] += dir.offset();
+        for i in 1..knots.len() {
+            let (head, tail) = (knots[i - 1], knots[i]);
+            if head.chebyshev(tail) > 1 {
+                knots[i] += (head - tail).signum();
+            }
+        }
+        let tail = knots[knots.len() - 1];
+        // HashSets are slow
+        if prev_tail != tail {
+            self.visited.insert(tail);
+        }
+        self.moved += 1;
+        self.skip_finished_motions();
+    }
+
+    fn is_done(&self) -> bool {
+        self.motion == self.motions.len()
+    }
+
+    fn snapshot(&self) -> Vec<Vec2> {
+        self.knots.clone()
+    }
+}
+
+pub fn answer_part1(inputs: &Input) -> usize {
+    let mut rope = Rope::new(inputs, 2);
+    rope.run();
+    rope.visited()
+}
+
+pub fn answer_part2(inputs: &Input) -> usize {
+    let mut rope = Rope::new(inputs, 10);
+    rope.run();
+    rope.visited()
+}
+
+/// `size` motions.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| format!("{} {}", rng.pick(&["L", "R", "U", "D"]), rng.range(1, 20)))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The trail left by the tail of a ten knot rope, with the knots where the
+/// motions leave them in red and the head in yellow.
+pub fn render(input: &Input) -> Grid<Rgb> {
+    let mut rope = Rope::new(input, 10);
+    rope.run();
+    let knots = rope.snapshot();
+    // The trail always holds the start, so the bounds are never empty.
+    let bounds = Bounds::from_points(rope.trail().iter().chain(&knots).copied()).unwrap();
+    let size = bounds.size();
+    let mut image = Grid::new(size.x as usize, size.y as usize, [0, 0, 0]);
+    for &v in rope.trail() {
+        image[v - bounds.min] = [255, 255, 255];
+    }
+    for &v in knots[1..].iter() {
+        image[v - bounds.min] = [255, 0, 0];
+    }
+    image[knots[0] - bounds.min] = [255, 255, 0];
+    image
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    const INPUT: &'static str = include_str!("inputs");
+
+    type Input = Input;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        parse(s)
+    }
+
+    fn answer_part1(input: &Input) -> usize {
+        answer_part1(input)
+    }
+
+    fn answer_part2(input: &Input) -> usize {
+        answer_part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+
+    fn render(input: &Input) -> Option<Grid<Rgb>> {
+        Some(render(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn _part1() {
+        let inputs = parse(include_str!("../examples/example1.txt")).unwrap();
+        assert_eq!(13, answer_part1(&inputs));
+    }
+    #[test]
+    fn _part2() {
+        let inputs = parse(include_str!("../examples/example2.txt")).unwrap();
+        assert_eq!(36, answer_part2(&inputs));
+    }
+
+    #[test]
+    fn _start_position() {
+        // The tail never gets back to where it started, which still counts.
+        let inputs = parse("R 3").unwrap();
+        assert_eq!(3, answer_part1(&inputs));
+        assert_eq!(1, answer_part2(&inputs));
+    }
+
+    #[test]
+    fn _rope_steps() {
+        let inputs = parse(include_str!("../examples/example1.txt")).unwrap();
+        let mut rope = Rope::new(&inputs, 2);
+        rope.step();
+        rope.step();
+        assert_eq!(vec![Vec2::new(2, 0), Vec2::new(1, 0)], rope.snapshot());
+        assert_eq!(22, rope.run());
+        assert!(rope.is_done());
+    }
+
+    #[test]
+    fn _render() {
+        let image = render(&parse(include_str!("../examples/example2.txt")).unwrap());
+        assert_eq!((22, 21), (image.width(), image.height()));
+        assert_eq!([255, 255, 255], image[(11, 15)]);
+        assert_eq!([255, 255, 0], image[(0, 0)]);
+    }
+
+    #[test]
+    fn _parse_error() {
+        let e = parse("R 4\nX 2").unwrap_err();
+        assert_eq!((2, 1), (e.line, e.column));
+    }
+
+    fn to_text(input: &Input) -> String {
+        let motions: Vec<String> = input
+            .iter()
+            .map(|(d, n)| {
+                let d = match d {
+                    D::Left => 'L',
+                    D::Right => 'R',
+                    D::Up => 'U',
+                    D::Down => 'D',
+                };
+                format!("{} {}", d, n)
+            })
+            .collect();
+        motions.join("\n")
+    }
+
+    proptest! {
+        #[test]
+        fn _round_trip(input in prop::collection::vec((
+            prop::sample::select(vec![D::Left, D::Right, D::Up, D::Down]),
+            any::<u8>(),
+        ), 1..10)) {
+            prop_assert_eq!(&input, &parse(&to_text(&input)).unwrap());
+        }
+    }
+}
@@ -0,0 +1,16 @@
+//! Throws arbitrary input at every day's `parse`, which must return an error
+//! rather than panic, and solves whatever it accepts, which must not panic
+//! either. Run with `cargo +nightly fuzz run parse` from `fuzz/`.
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        for day in aoc::days::DAYS {
+            if day.try_parse(input).is_ok() {
+                let _ = day.solve(input, None);
+            }
+        }
+    }
+});